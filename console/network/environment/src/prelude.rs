@@ -23,6 +23,7 @@ pub use snarkvm_utilities::{
     cfg_iter,
     cfg_iter_mut,
     error,
+    find_duplicate,
     has_duplicates,
     io::{Read, Result as IoResult, Write},
     FromBits as _,
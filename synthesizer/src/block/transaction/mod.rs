@@ -170,6 +170,16 @@ impl<N: Network> Transaction<N> {
         }
     }
 
+    /// Returns `true` if the transaction is a coinbase, i.e. an execution containing a coinbase transition.
+    pub fn is_coinbase(&self) -> bool {
+        match self {
+            Self::Deploy(..) => false,
+            Self::Execute(..) => self
+                .transitions()
+                .any(|transition| Program::is_coinbase(transition.program_id(), transition.function_name())),
+        }
+    }
+
     /// Returns the transaction fee, which is the sum of the transition fees.
     pub fn fee(&self) -> Result<i64> {
         // Compute the sum of the transition fees.
@@ -240,6 +250,11 @@ impl<N: Network> Transaction<N> {
 }
 
 impl<N: Network> Transaction<N> {
+    /// Returns the number of transitions in the transaction.
+    pub fn num_transitions(&self) -> usize {
+        self.transitions().count()
+    }
+
     /// Returns an iterator over the transition IDs, for all transitions.
     pub fn transition_ids(&self) -> impl '_ + Iterator<Item = &N::TransitionID> {
         self.transitions().map(Transition::id)
@@ -255,6 +270,14 @@ impl<N: Network> Transaction<N> {
         }
     }
 
+    /// Returns the fee transition, if one exists.
+    pub fn fee_transition(&self) -> Option<&Transition<N>> {
+        match self {
+            Self::Deploy(_, _, fee) => Some(fee.transition()),
+            Self::Execute(_, _, additional_fee) => additional_fee.as_ref().map(|fee| fee.transition()),
+        }
+    }
+
     /* Input */
 
     /// Returns an iterator over the input IDs, for all transition inputs that are records.
@@ -0,0 +1,237 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::collections::HashSet;
+
+/// A builder that assembles the transactions of a block, checking each transaction as it is added.
+#[derive(Clone)]
+pub struct TransactionsBuilder<N: Network> {
+    /// The transactions added so far.
+    transactions: IndexMap<N::TransactionID, Transaction<N>>,
+    /// The serial numbers of the transactions added so far.
+    serial_numbers: HashSet<Field<N>>,
+    /// The commitments of the transactions added so far.
+    commitments: HashSet<Field<N>>,
+    /// The number of coinbase transactions added so far.
+    num_coinbase: usize,
+    /// The number of bytes in the serialized transactions added so far.
+    num_bytes: usize,
+}
+
+impl<N: Network> Default for TransactionsBuilder<N> {
+    /// Initializes a new builder, with no transactions.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> TransactionsBuilder<N> {
+    /// Initializes a new builder, with no transactions.
+    pub fn new() -> Self {
+        Self {
+            transactions: IndexMap::new(),
+            serial_numbers: HashSet::new(),
+            commitments: HashSet::new(),
+            num_coinbase: 0,
+            // Note: The serialized transactions always contain the version and the number of transactions.
+            num_bytes: size::serialized_size(&0u16) + size::serialized_size(&0u32),
+        }
+    }
+
+    /// Returns the number of transactions added so far.
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns `true` if no transactions have been added.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Returns the number of bytes in the serialized transactions added so far.
+    pub fn size_in_bytes(&self) -> usize {
+        self.num_bytes
+    }
+
+    /// Adds the given transaction, after checking it against the transactions added so far.
+    /// If the check fails, the transaction is not added, and the first violation is returned.
+    pub fn add(&mut self, transaction: Transaction<N>) -> Result<(), TransactionsError<N>> {
+        let num_bytes = size::serialized_size(&transaction);
+        self.add_with_size(transaction, num_bytes)
+    }
+
+    /// Adds the given transaction, if it passes the checks of `add`, and the resulting transactions
+    /// are at most `max_bytes` bytes. Returns `false`, without adding the transaction, if a check fails
+    /// or the transaction does not fit. Note: This method errors if the transaction ID is incorrect.
+    pub fn try_add(&mut self, transaction: Transaction<N>, max_bytes: usize) -> Result<bool, TransactionsError<N>> {
+        // Ensure the resulting transactions fit within the size limit.
+        let num_bytes = size::serialized_size(&transaction);
+        if self.num_bytes.saturating_add(num_bytes) > max_bytes {
+            return Ok(false);
+        }
+        // Add the transaction.
+        match self.add_with_size(transaction, num_bytes) {
+            Ok(()) => Ok(true),
+            Err(error @ TransactionsError::InvalidTransaction(..)) => Err(error),
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Adds the given transaction, which serializes to `num_bytes` bytes, after checking it against the transactions
+    /// added so far.
+    fn add_with_size(&mut self, transaction: Transaction<N>, num_bytes: usize) -> Result<(), TransactionsError<N>> {
+        // Ensure the transaction ID is correct, and the transaction was not already added.
+        if !super::verify::has_valid_id(&transaction) {
+            return Err(TransactionsError::InvalidTransaction(transaction.id()));
+        }
+        if self.transactions.contains_key(&transaction.id()) {
+            return Err(TransactionsError::DuplicateTransaction(transaction.id()));
+        }
+        // Ensure the maximum number of transactions is not exceeded.
+        Transactions::<N>::check_num_transactions(self.transactions.len() + 1)?;
+        // Ensure the transaction does not introduce a duplicate serial number.
+        let mut serial_numbers = HashSet::new();
+        for serial_number in transaction.serial_numbers() {
+            if self.serial_numbers.contains(serial_number) || !serial_numbers.insert(*serial_number) {
+                return Err(TransactionsError::DuplicateSerialNumber(*serial_number));
+            }
+        }
+        // Ensure the transaction does not introduce a duplicate commitment.
+        let mut commitments = HashSet::new();
+        for commitment in transaction.commitments() {
+            if self.commitments.contains(commitment) || !commitments.insert(*commitment) {
+                return Err(TransactionsError::DuplicateCommitment(*commitment));
+            }
+        }
+        // Ensure the number of coinbase transactions remains within the allowed range.
+        let num_coinbase = self.num_coinbase + transaction.is_coinbase() as usize;
+        if num_coinbase > Transactions::<N>::MAX_COINBASE_TRANSACTIONS {
+            return Err(TransactionsError::UnexpectedCoinbaseCount {
+                expected: Transactions::<N>::MAX_COINBASE_TRANSACTIONS,
+                found: num_coinbase,
+            });
+        }
+
+        // Add the transaction.
+        self.serial_numbers.extend(serial_numbers);
+        self.commitments.extend(commitments);
+        self.num_coinbase = num_coinbase;
+        self.num_bytes = self.num_bytes.saturating_add(num_bytes);
+        self.transactions.insert(transaction.id(), transaction);
+        Ok(())
+    }
+
+    /// Returns the transactions, in the order they were added.
+    pub fn build(self) -> Transactions<N> {
+        Transactions { transactions: self.transactions, root: Default::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_build() {
+        let rng = &mut TestRng::default();
+
+        let coinbase = crate::block::transactions::test_helpers::sample_coinbase_transaction(rng);
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let execution = crate::vm::test_helpers::sample_execution_transaction(rng);
+
+        // Ensure the transactions are built in the order they were added.
+        let mut builder = TransactionsBuilder::<CurrentNetwork>::new();
+        for transaction in [coinbase.clone(), deployment.clone(), execution.clone()] {
+            builder.add(transaction).unwrap();
+        }
+        assert_eq!(builder.len(), 3);
+        let transactions = builder.build();
+        assert_eq!(transactions, Transactions::from(&[coinbase, deployment, execution]));
+        assert!(transactions.is_valid());
+
+        // Ensure an empty builder builds empty transactions.
+        assert!(TransactionsBuilder::<CurrentNetwork>::default().build().is_empty());
+    }
+
+    #[test]
+    fn test_add_duplicate_serial_number() {
+        let rng = &mut TestRng::default();
+
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+
+        // Construct a transaction that spends the same fee record as the deployment.
+        let duplicate = crate::block::transactions::test_helpers::sample_duplicate_transaction(rng);
+
+        // Ensure the duplicate serial number is reported on insert.
+        let mut builder = TransactionsBuilder::<CurrentNetwork>::new();
+        builder.add(deployment.clone()).unwrap();
+        let serial_number = *deployment.serial_numbers().next().unwrap();
+        assert!(matches!(
+            builder.add(duplicate),
+            Err(TransactionsError::DuplicateSerialNumber(candidate)) if candidate == serial_number
+        ));
+
+        // Ensure a duplicate transaction is reported on insert.
+        assert!(matches!(
+            builder.add(deployment.clone()),
+            Err(TransactionsError::DuplicateTransaction(id)) if id == deployment.id()
+        ));
+        // Ensure the rejected transactions were not added.
+        assert_eq!(builder.build(), Transactions::from_iter([deployment]));
+    }
+
+    #[test]
+    fn test_try_add() {
+        let rng = &mut TestRng::default();
+
+        let coinbase = crate::block::transactions::test_helpers::sample_coinbase_transaction(rng);
+        let (_, coinbase_1) = crate::block::test_helpers::sample_block_and_transaction(rng);
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+
+        // Ensure a transaction that fits is added.
+        let mut builder = TransactionsBuilder::<CurrentNetwork>::new();
+        assert_eq!(builder.size_in_bytes(), Transactions::<CurrentNetwork>::from(&[]).size_in_bytes());
+        assert!(builder.try_add(coinbase.clone(), usize::MAX).unwrap());
+        assert_eq!(builder.len(), 1);
+
+        // Ensure a transaction that does not fit is not added.
+        let size = builder.size_in_bytes();
+        assert!(!builder.try_add(deployment.clone(), size).unwrap());
+        assert_eq!(builder.len(), 1);
+        let max_bytes = size + deployment.to_bytes_le().unwrap().len();
+        assert!(builder.try_add(deployment.clone(), max_bytes).unwrap());
+        assert_eq!(builder.size_in_bytes(), max_bytes);
+
+        // Ensure an existing transaction, or a second coinbase transaction, is not added.
+        assert!(!builder.try_add(deployment.clone(), usize::MAX).unwrap());
+        assert!(!builder.try_add(coinbase_1, usize::MAX).unwrap());
+        let transactions = builder.build();
+        assert_eq!(transactions.size_in_bytes(), max_bytes);
+        assert_eq!(transactions, Transactions::from(&[coinbase.clone(), deployment]));
+
+        // Ensure a transaction that reuses a commitment is not added.
+        let duplicate = crate::block::transactions::test_helpers::sample_duplicate_transaction(rng);
+        let mut builder = TransactionsBuilder::<CurrentNetwork>::new();
+        builder.add(coinbase.clone()).unwrap();
+        assert!(!builder.try_add(duplicate, usize::MAX).unwrap());
+        assert_eq!(builder.build(), Transactions::from_iter([coinbase]));
+    }
+}
@@ -16,10 +16,9 @@
 
 use super::*;
 
-impl<N: Network> FromBytes for Transactions<N> {
-    /// Reads the transactions from buffer.
-    #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+impl<N: Network> Transactions<N> {
+    /// Reads the transactions from buffer, ensuring there are at most `max_transactions` transactions.
+    pub fn read_le_with_limit<R: Read>(mut reader: R, max_transactions: usize) -> IoResult<Self> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
@@ -28,10 +27,28 @@ impl<N: Network> FromBytes for Transactions<N> {
         }
         // Read the number of transactions.
         let num_txs: u32 = FromBytes::read_le(&mut reader)?;
+        // Ensure the number of transactions is within the limit.
+        if num_txs as usize > max_transactions {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Found {num_txs} transactions, exceeding the limit of {max_transactions}"),
+            ));
+        }
         // Read the transactions.
-        let transactions = (0..num_txs).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        let mut transactions = Vec::with_capacity((num_txs as usize).min(1024));
+        for _ in 0..num_txs {
+            transactions.push(Transaction::read_le(&mut reader)?);
+        }
         // Return the transactions.
-        Ok(Self::from(&transactions))
+        Ok(Self::from_iter(transactions))
+    }
+}
+
+impl<N: Network> FromBytes for Transactions<N> {
+    /// Reads the transactions from buffer.
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_with_limit(reader, Self::MAX_TRANSACTIONS)
     }
 }
 
@@ -52,9 +69,12 @@ impl<N: Network> ToBytes for Transactions<N> {
 mod tests {
     use super::*;
     use console::network::Testnet3;
+    use rand::seq::SliceRandom;
 
     type CurrentNetwork = Testnet3;
 
+    const ITERATIONS: usize = 10;
+
     #[test]
     fn test_bytes() -> Result<()> {
         let mut rng = TestRng::default();
@@ -67,4 +87,78 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_empty() -> Result<()> {
+        // Ensure empty transactions are written as the version and a zero prefix.
+        let expected = Transactions::<CurrentNetwork>::from(&[]);
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected_bytes, [0u16.to_bytes_le()?, 0u32.to_bytes_le()?].concat());
+
+        // Ensure the bytes round-trip exactly.
+        let candidate = Transactions::<CurrentNetwork>::read_le(&expected_bytes[..])?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_random_lengths() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample distinct transactions.
+        let transactions = [
+            crate::vm::test_helpers::sample_genesis_block(&mut rng).transactions().iter().next().unwrap().clone(),
+            crate::vm::test_helpers::sample_deployment_transaction(&mut rng),
+            crate::vm::test_helpers::sample_execution_transaction(&mut rng),
+        ];
+
+        for _ in 0..ITERATIONS {
+            // Select a random number of the transactions, in a random order.
+            let num_transactions = rng.gen_range(0..=transactions.len());
+            let mut selected = transactions.to_vec();
+            selected.shuffle(&mut rng);
+            let expected = Transactions::<CurrentNetwork>::from(&selected[..num_transactions]);
+            assert_eq!(expected.len(), num_transactions);
+
+            // Ensure the bytes round-trip exactly.
+            let expected_bytes = expected.to_bytes_le()?;
+            let candidate = Transactions::read_le(&expected_bytes[..])?;
+            assert_eq!(expected, candidate);
+            assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_le_with_limit() -> Result<()> {
+        // Prepare the bytes for an oversized number of transactions.
+        let mut bytes = 0u16.to_bytes_le()?;
+        bytes.extend(u32::MAX.to_bytes_le()?);
+
+        // Ensure the oversized number of transactions is rejected.
+        let error = Transactions::<CurrentNetwork>::read_le(&bytes[..]).err().unwrap();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        let error = Transactions::<CurrentNetwork>::read_le_with_limit(&bytes[..], 1).err().unwrap();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+        // Ensure empty transactions are read within any limit.
+        let expected = Transactions::<CurrentNetwork>::from(&[]);
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Transactions::read_le_with_limit(&expected_bytes[..], 0)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_le_with_limit_exceeded() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure the limit is enforced on the number of transactions.
+        let expected = crate::vm::test_helpers::sample_genesis_block(&mut rng).transactions().clone();
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Transactions::read_le_with_limit(&expected_bytes[..], expected.len())?);
+        let error = Transactions::<CurrentNetwork>::read_le_with_limit(&expected_bytes[..], expected.len() - 1);
+        assert_eq!(error.err().unwrap().kind(), std::io::ErrorKind::InvalidData);
+        Ok(())
+    }
 }
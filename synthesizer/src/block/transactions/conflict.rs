@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::collections::HashSet;
+
+/// An element shared by two sets of transactions, which prevents both sets from being included.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Conflict<N: Network> {
+    /// A serial number spent in both sets of transactions.
+    SerialNumber(Field<N>),
+    /// A commitment created in both sets of transactions.
+    Commitment(Field<N>),
+}
+
+impl<N: Network> Transactions<N> {
+    /// Returns the first serial number or commitment shared with the given transactions, if one exists.
+    /// Note: Serial numbers are checked before commitments.
+    pub fn conflicts_with(&self, other: &Transactions<N>) -> Option<Conflict<N>> {
+        // Find the first shared serial number.
+        let serial_numbers = self.serial_numbers().collect::<HashSet<_>>();
+        if let Some(serial_number) = other.serial_numbers().find(|serial_number| serial_numbers.contains(serial_number))
+        {
+            return Some(Conflict::SerialNumber(*serial_number));
+        }
+        // Find the first shared commitment.
+        let commitments = self.commitments().collect::<HashSet<_>>();
+        other
+            .commitments()
+            .find(|commitment| commitments.contains(commitment))
+            .map(|commitment| Conflict::Commitment(*commitment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_conflicts_with() {
+        let rng = &mut TestRng::default();
+
+        let coinbase = crate::block::transactions::test_helpers::sample_coinbase_transaction(rng);
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+
+        // Construct a transaction that reuses the coinbase execution, and the fee of the deployment.
+        let duplicate = Transactions::<CurrentNetwork>::from_iter([
+            crate::block::transactions::test_helpers::sample_duplicate_transaction(rng),
+        ]);
+
+        let coinbase = Transactions::from_iter([coinbase]);
+        let deployment = Transactions::from_iter([deployment]);
+
+        // Ensure disjoint transactions do not conflict.
+        assert_eq!(coinbase.conflicts_with(&deployment), None);
+        assert_eq!(deployment.conflicts_with(&coinbase), None);
+
+        // Ensure a shared serial number is reported.
+        let serial_number = *deployment.serial_numbers().next().unwrap();
+        assert_eq!(deployment.conflicts_with(&duplicate), Some(Conflict::SerialNumber(serial_number)));
+        assert_eq!(duplicate.conflicts_with(&deployment), Some(Conflict::SerialNumber(serial_number)));
+
+        // Ensure a shared commitment is reported.
+        let commitment = *coinbase.commitments().next().unwrap();
+        assert_eq!(coinbase.conflicts_with(&duplicate), Some(Conflict::Commitment(commitment)));
+    }
+}
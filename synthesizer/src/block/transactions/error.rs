@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The errors returned when checking the validity of the transactions in a block.
+#[derive(Debug, Error)]
+pub enum TransactionsError<N: Network> {
+    #[error("Transaction '{0}' is invalid")]
+    InvalidTransaction(N::TransactionID),
+    #[error("Found a duplicate transaction '{0}' in the transactions")]
+    DuplicateTransaction(N::TransactionID),
+    #[error("Found a duplicate serial number '{0}' in the transactions")]
+    DuplicateSerialNumber(Field<N>),
+    #[error("Found a duplicate commitment '{0}' in the transactions")]
+    DuplicateCommitment(Field<N>),
+    #[error("Expected at most {expected} coinbase transactions, found {found}")]
+    UnexpectedCoinbaseCount { expected: usize, found: usize },
+    #[error("Expected at most {max_transactions} transactions, found {num_transactions}")]
+    TooManyTransactions { num_transactions: usize, max_transactions: usize },
+    #[error("Transaction '{transaction_id}' contains {num_transitions} transitions, exceeding the maximum of {max_transitions}")]
+    TooManyTransitions { transaction_id: N::TransactionID, num_transitions: usize, max_transitions: usize },
+    #[error("Transaction '{transaction_id}' contains an invalid fee transition '{transition_id}': {reason}")]
+    InvalidFeeTransition { transaction_id: N::TransactionID, transition_id: N::TransitionID, reason: String },
+    #[error("The transactions do not contain a coinbase transaction")]
+    MissingCoinbase,
+    #[error("Failed to decrypt the coinbase record '{commitment}' in transaction '{transaction_id}'")]
+    UndecryptableCoinbaseRecord { transaction_id: N::TransactionID, commitment: Field<N> },
+    #[error("Transaction '{transaction_id}' pays the coinbase to an unexpected address '{recipient}'")]
+    UnexpectedCoinbaseRecipient { transaction_id: N::TransactionID, recipient: Address<N> },
+}
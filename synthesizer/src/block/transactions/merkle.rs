@@ -18,8 +18,21 @@ use super::*;
 
 impl<N: Network> Transactions<N> {
     /// Returns the transactions root, by computing the root for a Merkle tree of the transaction IDs.
+    /// Note: The root is computed once, and cached until `clear_cache` is called.
     pub fn to_root(&self) -> Result<Field<N>> {
-        Ok(*self.to_tree()?.root())
+        self.root.get_or_try_init(|| Ok(*self.to_tree()?.root())).copied()
+    }
+
+    /// Clears the cached transactions root.
+    /// Note: Any method that mutates the transactions must call this method.
+    pub fn clear_cache(&mut self) {
+        self.root.take();
+    }
+
+    /// Returns `true` if the transactions root matches the given root, such as the transactions root in a block header.
+    /// Note: This is the canonical check that the transactions are the ones committed to by a block header.
+    pub fn verify_transactions_root(&self, expected: &Field<N>) -> Result<bool> {
+        Ok(self.to_root()? == *expected)
     }
 
     /// Returns the Merkle path for the transactions leaf.
@@ -30,6 +43,35 @@ impl<N: Network> Transactions<N> {
         }
     }
 
+    /// Returns `true` if the given Merkle path proves the transaction ID is included under the given transactions root.
+    pub fn verify_transaction_inclusion(
+        root: &Field<N>,
+        transaction_id: &N::TransactionID,
+        path: &TransactionsPath<N>,
+    ) -> bool {
+        N::verify_merkle_path_bhp(path, root, &transaction_id.to_bits_le())
+    }
+
+    /// Returns the rolling transactions root, by folding the transaction IDs into the given previous root.
+    ///
+    /// The rolling root is computed sequentially over the transaction IDs, in order, as:
+    ///     root_0 := previous_root
+    ///     root_{i+1} := BHP1024(root_i || transaction_id_i)
+    /// where `||` concatenates the little-endian bits of each field element.
+    /// The fold is not associative, so verifiers must process the transactions in block order.
+    /// If there are no transactions, the previous root is returned unchanged.
+    pub fn to_rolling_root(&self, previous_root: &Field<N>) -> Result<Field<N>> {
+        self.transactions.keys().try_fold(*previous_root, |root, transaction_id| {
+            N::hash_bhp1024(&[root.to_bits_le(), transaction_id.to_bits_le()].concat())
+        })
+    }
+
+    /// Returns the Merkle leaves of the transactions tree, in order.
+    /// Note: Each leaf is the little-endian bits of a transaction ID, and is hashed into the tree with BHP1024.
+    pub fn to_leaves(&self) -> Vec<Vec<bool>> {
+        Self::transactions_leaves(&self.transactions)
+    }
+
     /// The Merkle tree of transaction IDs for the block.
     pub fn to_tree(&self) -> Result<TransactionsTree<N>> {
         Self::transactions_tree(&self.transactions)
@@ -44,10 +86,16 @@ impl<N: Network> Transactions<N> {
             Self::MAX_TRANSACTIONS,
             transactions.len()
         );
-        // Prepare the leaves.
-        let leaves = transactions.values().map(|transaction| transaction.id().to_bits_le());
         // Compute the deployment tree.
-        N::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&leaves.collect::<Vec<_>>())
+        N::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&Self::transactions_leaves(transactions))
+    }
+
+    /// Returns the Merkle leaves for the given transactions.
+    fn transactions_leaves(transactions: &IndexMap<N::TransactionID, Transaction<N>>) -> Vec<Vec<bool>> {
+        match cfg!(feature = "parallel") {
+            true => transactions.par_values().map(|transaction| transaction.id().to_bits_le()).collect(),
+            false => transactions.values().map(|transaction| transaction.id().to_bits_le()).collect(),
+        }
     }
 }
 
@@ -63,4 +111,140 @@ mod tests {
         // Ensure the log2 relationship between depth and the maximum number of transactions.
         assert_eq!(2usize.pow(TRANSACTIONS_DEPTH as u32), Transactions::<CurrentNetwork>::MAX_TRANSACTIONS);
     }
+
+    #[test]
+    fn test_verify_transaction_inclusion() {
+        let rng = &mut TestRng::default();
+
+        let transaction_0 = crate::block::transactions::test_helpers::sample_coinbase_transaction(rng);
+        let transaction_1 = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let transactions = Transactions::<CurrentNetwork>::from(&[transaction_0.clone(), transaction_1.clone()]);
+        let root = transactions.to_root().unwrap();
+
+        // Ensure the path of each transaction verifies against the root.
+        for transaction_id in [transaction_0.id(), transaction_1.id()] {
+            let path = transactions.to_path(transaction_id).unwrap();
+            assert!(Transactions::verify_transaction_inclusion(&root, &transaction_id, &path));
+        }
+
+        // Ensure a path does not verify for a different transaction or root.
+        let path = transactions.to_path(transaction_0.id()).unwrap();
+        assert!(!Transactions::verify_transaction_inclusion(&root, &transaction_1.id(), &path));
+        assert!(!Transactions::verify_transaction_inclusion(&Field::rand(rng), &transaction_0.id(), &path));
+
+        // Ensure a path cannot be computed for a missing transaction.
+        let transactions = Transactions::<CurrentNetwork>::from_iter([transaction_0]);
+        assert!(transactions.to_path(transaction_1.id()).is_err());
+    }
+
+    #[test]
+    fn test_to_root_matches_serial_leaves() {
+        let rng = &mut TestRng::default();
+
+        let transaction_0 = crate::block::transactions::test_helpers::sample_coinbase_transaction(rng);
+        let transaction_1 = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let transaction_2 = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let transactions = Transactions::<CurrentNetwork>::from(&[transaction_0, transaction_1, transaction_2]);
+
+        // Compute the root from serially-prepared leaves.
+        let leaves = transactions.values().map(|transaction| transaction.id().to_bits_le()).collect::<Vec<_>>();
+        let expected_root = *CurrentNetwork::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&leaves).unwrap().root();
+
+        // Ensure the root matches, regardless of how the leaves are prepared.
+        assert_eq!(transactions.to_root().unwrap(), expected_root);
+    }
+
+    #[test]
+    fn test_verify_transactions_root() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the transactions match the root in the block header.
+        let block = crate::vm::test_helpers::sample_genesis_block(rng);
+        let transactions = block.transactions();
+        assert!(transactions.verify_transactions_root(&block.transactions_root()).unwrap());
+
+        // Ensure a tampered root is rejected.
+        assert!(!transactions.verify_transactions_root(&Field::rand(rng)).unwrap());
+        assert!(!transactions.verify_transactions_root(&(block.transactions_root() + Field::one())).unwrap());
+    }
+
+    #[test]
+    fn test_to_leaves() {
+        let rng = &mut TestRng::default();
+
+        // Ensure there is one leaf per transaction.
+        let transactions = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        let leaves = transactions.to_leaves();
+        assert_eq!(leaves.len(), transactions.len());
+
+        // Ensure the leaves reproduce the transactions root.
+        let tree = CurrentNetwork::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&leaves).unwrap();
+        assert_eq!(*tree.root(), transactions.to_root().unwrap());
+
+        // Ensure empty transactions have no leaves.
+        assert!(Transactions::<CurrentNetwork>::from(&[]).to_leaves().is_empty());
+    }
+
+    #[test]
+    fn test_to_root_cache() {
+        let rng = &mut TestRng::default();
+
+        let mut transactions = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        let expected_root = *transactions.to_tree().unwrap().root();
+
+        // Ensure the cached root matches a freshly computed root.
+        assert_eq!(transactions.to_root().unwrap(), expected_root);
+        assert_eq!(transactions.to_root().unwrap(), expected_root);
+
+        // Ensure the root is recomputed after clearing the cache.
+        transactions.clear_cache();
+        assert_eq!(transactions.to_root().unwrap(), expected_root);
+
+        // Ensure the cache does not affect equality.
+        let uncached = transactions.iter().collect::<Transactions<_>>();
+        assert_eq!(transactions, uncached);
+    }
+
+    #[test]
+    fn test_to_root_empty() {
+        let transactions = Transactions::<CurrentNetwork>::from(&[]);
+
+        // Ensure the root of empty transactions is the root of an empty tree.
+        let expected_root = *CurrentNetwork::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&[]).unwrap().root();
+        assert_eq!(transactions.to_root().unwrap(), expected_root);
+        assert_eq!(*transactions.to_tree().unwrap().root(), expected_root);
+
+        // Ensure a path cannot be computed for a missing transaction.
+        assert!(transactions.to_path(Default::default()).is_err());
+    }
+
+    #[test]
+    fn test_to_rolling_root() {
+        let rng = &mut TestRng::default();
+
+        let transaction_0 = crate::block::transactions::test_helpers::sample_coinbase_transaction(rng);
+        let transaction_1 = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let previous_root = Field::<CurrentNetwork>::rand(rng);
+
+        // Ensure an empty block leaves the rolling root unchanged.
+        let transactions = Transactions::<CurrentNetwork>::from(&[]);
+        assert_eq!(transactions.to_rolling_root(&previous_root).unwrap(), previous_root);
+
+        // Ensure the rolling root matches the documented fold.
+        let transactions = Transactions::from(&[transaction_0.clone(), transaction_1.clone()]);
+        let expected = [transaction_0.id(), transaction_1.id()].iter().fold(previous_root, |root, id| {
+            CurrentNetwork::hash_bhp1024(&[root.to_bits_le(), id.to_bits_le()].concat()).unwrap()
+        });
+        let rolling_root = transactions.to_rolling_root(&previous_root).unwrap();
+        assert_eq!(rolling_root, expected);
+
+        // Ensure the rolling root can be folded across consecutive blocks.
+        let first = Transactions::from_iter([transaction_0.clone()]).to_rolling_root(&previous_root).unwrap();
+        assert_eq!(Transactions::from_iter([transaction_1.clone()]).to_rolling_root(&first).unwrap(), rolling_root);
+
+        // Ensure the rolling root depends on the order and the previous root.
+        let reordered = Transactions::from(&[transaction_1, transaction_0]);
+        assert_ne!(reordered.to_rolling_root(&previous_root).unwrap(), rolling_root);
+        assert_ne!(transactions.to_rolling_root(&Field::rand(rng)).unwrap(), rolling_root);
+    }
 }
@@ -14,30 +14,77 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod builder;
+pub use builder::*;
+
+mod conflict;
+pub use conflict::*;
+
+mod error;
+pub use error::*;
+
+mod sparse;
+pub use sparse::*;
+
 mod bytes;
 mod merkle;
 mod serialize;
+mod size;
 mod string;
+mod verify;
 
 use crate::{
     block::{Transaction, Transition},
     process::{Deployment, Execution},
 };
 use console::{
+    account::{Address, ViewKey},
     network::prelude::*,
-    program::{Ciphertext, Record, TransactionsPath, TransactionsTree, TRANSACTIONS_DEPTH},
+    program::{
+        Ciphertext,
+        Identifier,
+        Plaintext,
+        ProgramID,
+        Record,
+        TransactionsPath,
+        TransactionsTree,
+        TRANSACTIONS_DEPTH,
+    },
     types::{Field, Group},
 };
 
 use indexmap::IndexMap;
+use once_cell::sync::OnceCell;
+use std::collections::BTreeSet;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct Transactions<N: Network> {
     /// The transactions included in a block.
     transactions: IndexMap<N::TransactionID, Transaction<N>>,
+    /// The cached transactions root.
+    root: OnceCell<Field<N>>,
+}
+
+impl<N: Network> PartialEq for Transactions<N> {
+    /// Returns `true` if the transactions are equal and in the same order, ignoring the cached transactions root.
+    fn eq(&self, other: &Self) -> bool {
+        self.transactions.len() == other.transactions.len() && self.transactions.iter().eq(other.transactions.iter())
+    }
+}
+
+impl<N: Network> Eq for Transactions<N> {}
+
+impl<N: Network> core::hash::Hash for Transactions<N> {
+    /// Hashes the transactions root, which commits to the transactions and their order.
+    /// Note: If the transactions root cannot be computed, a fixed sentinel is hashed instead.
+    /// The cached root only memoizes a value derived from the transactions, so the hash never changes,
+    /// and maps and sets keyed on `Transactions` may safely allow `clippy::mutable_key_type`.
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.to_root().ok().hash(state)
+    }
 }
 
 impl<N: Network> Transactions<N> {
@@ -50,7 +97,10 @@ impl<N: Network> Transactions<N> {
 impl<N: Network> FromIterator<Transaction<N>> for Transactions<N> {
     /// Initializes from an iterator of transactions.
     fn from_iter<T: IntoIterator<Item = Transaction<N>>>(iter: T) -> Self {
-        Self { transactions: iter.into_iter().map(|transaction| (transaction.id(), transaction)).collect() }
+        Self {
+            transactions: iter.into_iter().map(|transaction| (transaction.id(), transaction)).collect(),
+            root: OnceCell::new(),
+        }
     }
 }
 
@@ -62,6 +112,11 @@ impl<'a, N: Network> FromIterator<&'a Transaction<N>> for Transactions<N> {
 }
 
 impl<N: Network> Transactions<N> {
+    /// Returns `true` if the transactions contains the given transaction ID. This method is `O(1)`.
+    pub fn contains_transaction(&self, transaction_id: &N::TransactionID) -> bool {
+        self.transactions.contains_key(transaction_id)
+    }
+
     /// Returns `true` if the transactions contains the given transition ID.
     pub fn contains_transition(&self, transition_id: &N::TransitionID) -> bool {
         match cfg!(feature = "parallel") {
@@ -88,6 +143,11 @@ impl<N: Network> Transactions<N> {
 }
 
 impl<N: Network> Transactions<N> {
+    /// Returns the transaction with the given transaction ID, if it exists. This method is `O(1)`.
+    pub fn get_transaction(&self, transaction_id: &N::TransactionID) -> Option<&Transaction<N>> {
+        self.transactions.get(transaction_id)
+    }
+
     /// Returns the transaction with the given transition ID, if it exists.
     pub fn find_transaction_for_transition_id(&self, transition_id: &N::TransitionID) -> Option<&Transaction<N>> {
         match cfg!(feature = "parallel") {
@@ -149,6 +209,171 @@ impl<N: Network> Transactions<N> {
 }
 
 impl<N: Network> Transactions<N> {
+    /// Returns the decrypted records owned by the given view key, paired with their transaction ID.
+    /// Note: Records that do not belong to the view key are skipped.
+    #[allow(clippy::type_complexity)]
+    pub fn decryptable_records(
+        &self,
+        view_key: &ViewKey<N>,
+    ) -> Result<Vec<(N::TransactionID, Record<N, Plaintext<N>>)>> {
+        // Derive the address of the view key.
+        let address = view_key.to_address();
+        // Decrypts the records in the given transaction that are owned by the view key.
+        let decrypt = |transaction: &Transaction<N>| {
+            transaction
+                .records()
+                .filter(|(_, record)| record.is_owner(&address, view_key))
+                .map(|(_, record)| Ok((transaction.id(), record.decrypt(view_key)?)))
+                .collect::<Result<Vec<_>>>()
+        };
+        // Decrypt the records of each transaction, preserving the order of the transactions.
+        let records = match cfg!(feature = "parallel") {
+            true => self.par_values().map(decrypt).collect::<Result<Vec<_>>>()?,
+            false => self.values().map(decrypt).collect::<Result<Vec<_>>>()?,
+        };
+        // Return the records.
+        Ok(records.into_iter().flatten().collect())
+    }
+}
+
+impl<N: Network> Transactions<N> {
+    /// Returns the coinbase transaction, if one exists.
+    pub fn coinbase_transaction(&self) -> Option<&Transaction<N>> {
+        self.values().find(|transaction| transaction.is_coinbase())
+    }
+
+    /// Returns the number of coinbase transactions.
+    /// Note: A coinbase transaction is identified by its `credits.aleo/mint` transition, not by its fee,
+    /// so a transaction with a negative fee is not counted unless it contains the coinbase transition.
+    pub fn num_coinbase_transactions(&self) -> usize {
+        self.values().filter(|transaction| transaction.is_coinbase()).count()
+    }
+
+    /// Returns `true` if the transactions contain exactly the expected number of coinbase transactions.
+    pub fn has_coinbase(&self) -> bool {
+        self.num_coinbase_transactions() == Self::MAX_COINBASE_TRANSACTIONS
+    }
+
+    /// Returns the coinbase transaction, and the remaining transactions.
+    /// Note: This method errors if there is not exactly one coinbase transaction.
+    #[allow(clippy::type_complexity)]
+    pub fn split_coinbase(&self) -> Result<(&Transaction<N>, Vec<&Transaction<N>>), TransactionsError<N>> {
+        // Partition the coinbase transactions from the remaining transactions.
+        let (coinbase, transactions): (Vec<_>, Vec<_>) =
+            self.values().partition(|transaction| transaction.is_coinbase());
+        // Ensure there is exactly one coinbase transaction.
+        match coinbase.as_slice() {
+            [coinbase] => Ok((coinbase, transactions)),
+            [] => Err(TransactionsError::MissingCoinbase),
+            _ => Err(TransactionsError::UnexpectedCoinbaseCount {
+                expected: Self::MAX_COINBASE_TRANSACTIONS,
+                found: coinbase.len(),
+            }),
+        }
+    }
+}
+
+impl<N: Network> Transactions<N> {
+    /// Appends the given transaction.
+    /// Note: This method errors if the transaction already exists, if the maximum number of transactions
+    /// is reached, or if the transaction is a second coinbase transaction.
+    pub fn push(&mut self, transaction: Transaction<N>) -> Result<()> {
+        // Ensure the maximum number of transactions is not exceeded.
+        Self::check_num_transactions(self.transactions.len() + 1)?;
+        // Ensure the transaction does not already exist.
+        ensure!(!self.contains_transaction(&transaction.id()), "Transaction '{}' already exists", transaction.id());
+        // Ensure the number of coinbase transactions remains within the allowed range.
+        if transaction.is_coinbase() && self.coinbase_transaction().is_some() {
+            return Err(TransactionsError::<N>::UnexpectedCoinbaseCount {
+                expected: Self::MAX_COINBASE_TRANSACTIONS,
+                found: Self::MAX_COINBASE_TRANSACTIONS + 1,
+            }
+            .into());
+        }
+        // Append the transaction.
+        self.transactions.insert(transaction.id(), transaction);
+        // Clear the cached transactions root.
+        self.clear_cache();
+        Ok(())
+    }
+
+    /// Returns the transactions of `self`, followed by the transactions of `other`.
+    /// Note: This method errors if the combined transactions are invalid, or do not contain exactly one coinbase.
+    pub fn merge(self, other: Transactions<N>) -> Result<Self, TransactionsError<N>> {
+        // Add each transaction, checking for duplicates across both sets.
+        let mut builder = TransactionsBuilder::new();
+        for transaction in self.into_iter().chain(other) {
+            builder.add(transaction)?;
+        }
+        let transactions = builder.build();
+        // Ensure there is a coinbase transaction.
+        match transactions.coinbase_transaction() {
+            Some(_) => Ok(transactions),
+            None => Err(TransactionsError::MissingCoinbase),
+        }
+    }
+
+    /// Appends the given coinbase transaction.
+    /// Note: This method errors if the transaction is not a coinbase transaction,
+    /// or if a coinbase transaction already exists.
+    pub fn push_coinbase(&mut self, transaction: Transaction<N>) -> Result<()> {
+        // Ensure the transaction is a coinbase transaction.
+        ensure!(transaction.is_coinbase(), "Transaction '{}' is not a coinbase transaction", transaction.id());
+        // Append the transaction.
+        self.push(transaction)
+    }
+}
+
+impl<N: Network> Transactions<N> {
+    /// Sorts the transactions into the canonical order.
+    ///
+    /// The canonical order pins the coinbase transaction first, and orders the remaining transactions
+    /// by the little-endian bytes of their transaction IDs.
+    pub fn sort_canonical(&mut self) -> Result<()> {
+        // Compute the canonical key of each transaction.
+        let keys = self.values().map(canonical_key).collect::<Result<Vec<_>>>()?;
+        // Sort the transactions by their canonical keys.
+        let mut entries = keys.into_iter().zip_eq(core::mem::take(&mut self.transactions)).collect::<Vec<_>>();
+        entries.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+        self.transactions = entries.into_iter().map(|(_, entry)| entry).collect();
+        // Clear the cached transactions root.
+        self.clear_cache();
+        Ok(())
+    }
+
+    /// Returns `true` if the transactions are in the canonical order.
+    pub fn is_canonically_ordered(&self) -> Result<bool> {
+        let keys = self.values().map(canonical_key).collect::<Result<Vec<_>>>()?;
+        Ok(keys.windows(2).all(|pair| pair[0] <= pair[1]))
+    }
+}
+
+/// Returns the key of the given transaction in the canonical order.
+fn canonical_key<N: Network>(transaction: &Transaction<N>) -> Result<(bool, Vec<u8>)> {
+    Ok((!transaction.is_coinbase(), transaction.id().to_bytes_le()?))
+}
+
+impl<N: Network> Transactions<N> {
+    /// Returns a 64-bit fingerprint of the ordered transaction IDs, for peers to cheaply compare block bodies.
+    /// Note: Collisions are possible, so the fingerprint is a hint, not a proof. Use the transactions root to verify.
+    pub fn gossip_fingerprint(&self) -> Result<[u8; 8]> {
+        use blake2::Digest;
+
+        // Hash the transaction IDs, in order.
+        let mut hasher = blake2::Blake2s256::new();
+        for transaction_id in self.transaction_ids() {
+            hasher.update(transaction_id.to_bytes_le()?);
+        }
+        // Truncate the hash to 64 bits.
+        let mut fingerprint = [0u8; 8];
+        fingerprint.copy_from_slice(&hasher.finalize()[..8]);
+        Ok(fingerprint)
+    }
+}
+
+impl<N: Network> Transactions<N> {
+    /// The maximum number of coinbase transactions allowed in a block.
+    pub const MAX_COINBASE_TRANSACTIONS: usize = 1;
     /// The maximum number of transactions allowed in a block.
     pub const MAX_TRANSACTIONS: usize = usize::pow(2, TRANSACTIONS_DEPTH as u32);
 
@@ -157,6 +382,31 @@ impl<N: Network> Transactions<N> {
         self.transactions.values()
     }
 
+    /// Returns the number of transactions.
+    pub fn num_transactions(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns the number of transitions, for all transactions in `self`.
+    pub fn num_transitions(&self) -> usize {
+        self.values().map(Transaction::num_transitions).sum()
+    }
+
+    /// Returns the number of serial numbers, for all transactions in `self`.
+    pub fn num_serial_numbers(&self) -> usize {
+        self.serial_numbers().count()
+    }
+
+    /// Returns the number of commitments, for all transactions in `self`.
+    pub fn num_commitments(&self) -> usize {
+        self.commitments().count()
+    }
+
+    /// Returns the distinct program IDs of the transitions, for all transactions in `self`.
+    pub fn program_ids(&self) -> BTreeSet<ProgramID<N>> {
+        self.transitions().map(|transition| *transition.program_id()).collect()
+    }
+
     /// Returns an iterator over the transaction IDs, for all transactions in `self`.
     pub fn transaction_ids(&self) -> impl '_ + Iterator<Item = &N::TransactionID> {
         self.transactions.keys()
@@ -217,6 +467,94 @@ impl<N: Network> Transactions<N> {
     pub fn fees(&self) -> impl '_ + Iterator<Item = &i64> {
         self.transitions().map(Transition::fee)
     }
+
+    /// Returns the IDs of the executions with at least one non-fee transition,
+    /// in which every non-fee transition has no inputs and no outputs.
+    pub fn find_no_effect_transactions(&self) -> Vec<N::TransactionID> {
+        // Prepare the program ID and function name of the fee.
+        let fee_function = fee_function::<N>().ok();
+        let is_fee = |transition: &Transition<N>| {
+            matches!(&fee_function, Some((program_id, function_name))
+                if transition.program_id() == program_id && transition.function_name() == function_name)
+        };
+
+        self.values()
+            .filter_map(|transaction| match transaction {
+                Transaction::Execute(id, execution, _) => {
+                    let mut transitions = execution.transitions().filter(|transition| !is_fee(transition)).peekable();
+                    let has_transitions = transitions.peek().is_some();
+                    (has_transitions
+                        && transitions
+                            .all(|transition| transition.inputs().is_empty() && transition.outputs().is_empty()))
+                    .then_some(*id)
+                }
+                Transaction::Deploy(..) => None,
+            })
+            .collect()
+    }
+
+    /// Returns the fee of each transaction, for all transactions that are not a coinbase.
+    pub fn transaction_fees(&self) -> Result<Vec<(N::TransactionID, i64)>> {
+        self.values()
+            .filter(|transaction| !transaction.is_coinbase())
+            .map(|transaction| Ok((transaction.id(), transaction.fee()?)))
+            .collect()
+    }
+
+    /// Returns the sum of the transaction fees, for all transactions that are not a coinbase.
+    pub fn to_transaction_fees(&self) -> Result<i64> {
+        checked_sum(self.values().filter(|transaction| !transaction.is_coinbase()).map(Transaction::fee))
+    }
+
+    /// Returns the net value balance, which is the sum of the fees of all transactions, including the coinbase.
+    pub fn to_net_value_balance(&self) -> Result<i64> {
+        checked_sum(self.values().map(Transaction::fee))
+    }
+
+    /// Returns the validator share and the remainder of the transaction fees,
+    /// for the given validator share in basis points (1/100th of a percent).
+    /// Note: The validator share is rounded down, so the remainder receives any rounding.
+    pub fn fee_split(&self, validator_bps: u16) -> Result<(i64, i64)> {
+        split_fees(self.to_transaction_fees()?, validator_bps)
+    }
+
+    /// Returns the fee transition of each transaction, for all transactions with a fee.
+    pub fn fee_transitions(&self) -> Result<Vec<(N::TransactionID, Transition<N>)>> {
+        Ok(self
+            .values()
+            .filter_map(|transaction| Some((transaction.id(), transaction.fee_transition()?.clone())))
+            .collect())
+    }
+}
+
+/// Returns the program ID and function name of the fee.
+fn fee_function<N: Network>() -> Result<(ProgramID<N>, Identifier<N>)> {
+    Ok((ProgramID::from_str("credits.aleo")?, Identifier::from_str("fee")?))
+}
+
+/// Returns the sum of the given fees, erroring if any fee is an error or if the sum overflows.
+fn checked_sum(mut fees: impl Iterator<Item = Result<i64>>) -> Result<i64> {
+    fees.try_fold(0i64, |cumulative, fee| {
+        cumulative.checked_add(fee?).ok_or_else(|| anyhow!("Value balance overflowed"))
+    })
+}
+
+/// The number of basis points in a whole.
+const MAX_BASIS_POINTS: u16 = 10_000;
+
+/// Returns the validator share and the remainder of the given fees, for the given validator share in basis points.
+fn split_fees(fees: i64, validator_bps: u16) -> Result<(i64, i64)> {
+    // Ensure the fees are not negative.
+    ensure!(!fees.is_negative(), "Cannot split negative fees ({fees})");
+    // Ensure the validator share does not exceed the whole.
+    ensure!(
+        validator_bps <= MAX_BASIS_POINTS,
+        "Validator share cannot exceed {MAX_BASIS_POINTS} basis points, found {validator_bps}"
+    );
+    // Compute the validator share, rounding down.
+    let validator = (fees as u128 * validator_bps as u128 / MAX_BASIS_POINTS as u128) as i64;
+    // Return the validator share and the remainder.
+    Ok((validator, fees - validator))
 }
 
 impl<N: Network> IntoIterator for Transactions<N> {
@@ -294,3 +632,507 @@ impl<N: Network> Deref for Transactions<N> {
         &self.transactions
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::vm::test_helpers::CurrentNetwork;
+
+    /// Samples the coinbase transaction of the genesis block.
+    pub(crate) fn sample_coinbase_transaction(rng: &mut TestRng) -> Transaction<CurrentNetwork> {
+        crate::vm::test_helpers::sample_genesis_block(rng).transactions().iter().next().unwrap().clone()
+    }
+
+    /// Samples a transaction that reuses the coinbase execution, under the fee of the sample deployment.
+    /// Note: The transaction shares its commitments with the coinbase, and its serial number with the deployment.
+    pub(crate) fn sample_duplicate_transaction(rng: &mut TestRng) -> Transaction<CurrentNetwork> {
+        let execution = match sample_coinbase_transaction(rng) {
+            Transaction::Execute(_, execution, _) => execution,
+            _ => unreachable!("Expected an execution transaction"),
+        };
+        let fee = match crate::vm::test_helpers::sample_deployment_transaction(rng) {
+            Transaction::Deploy(_, _, fee) => fee,
+            _ => unreachable!("Expected a deployment transaction"),
+        };
+        Transaction::from_execution(execution, Some(fee)).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        account::PrivateKey,
+        network::Testnet3,
+        program::{Identifier, Value},
+    };
+    use rand::seq::SliceRandom;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_num_metrics() {
+        let rng = &mut TestRng::default();
+
+        let transaction_0 = test_helpers::sample_coinbase_transaction(rng);
+        let transaction_1 = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let transaction_2 = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let transactions = Transactions::<CurrentNetwork>::from(&[transaction_0, transaction_1, transaction_2]);
+
+        // Ensure the counts match the lengths of the flattened iterators.
+        assert_eq!(transactions.num_transactions(), 3);
+        assert_eq!(transactions.num_transactions(), transactions.transaction_ids().count());
+        assert_eq!(transactions.num_transitions(), transactions.transitions().count());
+        assert_eq!(transactions.num_serial_numbers(), transactions.serial_numbers().collect::<Vec<_>>().len());
+        assert_eq!(transactions.num_commitments(), transactions.commitments().collect::<Vec<_>>().len());
+    }
+
+    #[test]
+    fn test_hash() {
+        let rng = &mut TestRng::default();
+
+        let transaction_0 = test_helpers::sample_coinbase_transaction(rng);
+        let transaction_1 = crate::vm::test_helpers::sample_deployment_transaction(rng);
+
+        // Ensure equal transactions are deduplicated.
+        // Note: The cached root only memoizes a value derived from the transactions, so the hash cannot change.
+        let transactions = Transactions::<CurrentNetwork>::from(&[transaction_0.clone(), transaction_1.clone()]);
+        #[allow(clippy::mutable_key_type)]
+        let mut set = std::collections::HashSet::new();
+        set.insert(transactions.clone());
+        set.insert(transactions.iter().collect::<Transactions<_>>());
+        assert_eq!(set.len(), 1);
+
+        // Ensure the same transactions in a different order are distinct.
+        let reordered = Transactions::<CurrentNetwork>::from(&[transaction_1, transaction_0]);
+        assert_ne!(transactions, reordered);
+        set.insert(reordered);
+        assert_eq!(set.len(), 2);
+
+        // Ensure empty transactions are hashable.
+        set.insert(Transactions::from(&[]));
+        set.insert(Transactions::from(&[]));
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn test_program_ids() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program.
+        let program = crate::Program::<CurrentNetwork>::from_str(
+            r"
+program ids.aleo;
+
+function double:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+
+        // Execute the program.
+        let process = crate::process::test_helpers::sample_process(&program);
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let authorization = process
+            .authorize::<circuit::network::AleoV0, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str("double").unwrap(),
+                &[Value::from_str("1u64").unwrap()],
+                rng,
+            )
+            .unwrap();
+        let (_, execution, _) = process.execute::<circuit::network::AleoV0, _>(authorization, rng).unwrap();
+        let transaction = Transaction::from_execution(execution, None).unwrap();
+
+        // Ensure the program IDs are distinct, and ordered.
+        let coinbase = test_helpers::sample_coinbase_transaction(rng);
+        let transfer = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let transactions = Transactions::<CurrentNetwork>::from(&[coinbase, transfer, transaction]);
+        let expected = [ProgramID::from_str("credits.aleo").unwrap(), *program.id()];
+        assert_eq!(transactions.program_ids().into_iter().collect::<Vec<_>>(), expected);
+
+        // Ensure empty transactions have no program IDs.
+        assert!(Transactions::<CurrentNetwork>::from(&[]).program_ids().is_empty());
+    }
+
+    #[test]
+    fn test_sort_canonical() {
+        let rng = &mut TestRng::default();
+
+        let coinbase = test_helpers::sample_coinbase_transaction(rng);
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let execution = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let mut transactions = vec![coinbase.clone(), deployment, execution];
+
+        // Compute the canonical order.
+        let mut expected = Transactions::<CurrentNetwork>::from(&transactions);
+        expected.sort_canonical().unwrap();
+        assert!(expected.is_canonically_ordered().unwrap());
+        assert_eq!(expected.iter().next(), Some(&coinbase));
+
+        for _ in 0..ITERATIONS {
+            // Shuffle the transactions.
+            transactions.shuffle(rng);
+            let mut candidate = Transactions::<CurrentNetwork>::from(&transactions);
+            candidate.to_root().unwrap();
+
+            // Ensure sorting yields a stable order, with unchanged membership.
+            candidate.sort_canonical().unwrap();
+            assert_eq!(candidate, expected);
+            assert_eq!(candidate.to_root().unwrap(), expected.to_root().unwrap());
+            assert_eq!(candidate.len(), transactions.len());
+            assert!(transactions.iter().all(|transaction| candidate.contains_transaction(&transaction.id())));
+        }
+
+        // Ensure empty transactions are canonically ordered.
+        assert!(Transactions::<CurrentNetwork>::from(&[]).is_canonically_ordered().unwrap());
+    }
+
+    #[test]
+    fn test_empty() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the accessors on empty transactions return empty results.
+        let transactions = Transactions::<CurrentNetwork>::from(&[]);
+        assert!(transactions.is_empty());
+        assert_eq!(transactions.transaction_ids().count(), 0);
+        assert_eq!(transactions.transitions().count(), 0);
+        assert_eq!(transactions.serial_numbers().count(), 0);
+        assert_eq!(transactions.commitments().count(), 0);
+        assert_eq!(transactions.fees().count(), 0);
+        assert_eq!(transactions.num_transactions(), 0);
+        assert_eq!(transactions.num_transitions(), 0);
+        assert_eq!(transactions.num_serial_numbers(), 0);
+        assert_eq!(transactions.num_commitments(), 0);
+        assert!(transactions.coinbase_transaction().is_none());
+        assert!(transactions.fee_transitions().unwrap().is_empty());
+        assert!(transactions.find_no_effect_transactions().is_empty());
+        assert!(transactions.validate().is_ok());
+
+        // Ensure decrypting records from empty transactions returns no records.
+        let view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(transactions.decryptable_records(&view_key).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_has_coinbase() {
+        let rng = &mut TestRng::default();
+
+        let coinbase_0 = test_helpers::sample_coinbase_transaction(rng);
+        let (_, coinbase_1) = crate::block::test_helpers::sample_block_and_transaction(rng);
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+
+        // Ensure the coinbase is identified by its transition, rather than its fee.
+        assert!(coinbase_0.fee().unwrap().is_negative());
+        assert!(coinbase_0.is_coinbase());
+        assert!(!deployment.fee().unwrap().is_negative());
+        assert!(!deployment.is_coinbase());
+
+        // Ensure exactly one coinbase is expected.
+        let transactions = Transactions::<CurrentNetwork>::from(&[deployment.clone(), coinbase_0.clone()]);
+        assert_eq!(transactions.num_coinbase_transactions(), 1);
+        assert!(transactions.has_coinbase());
+        let transactions = Transactions::<CurrentNetwork>::from_iter([deployment.clone()]);
+        assert_eq!(transactions.num_coinbase_transactions(), 0);
+        assert!(!transactions.has_coinbase());
+        let transactions = Transactions::<CurrentNetwork>::from(&[coinbase_0, deployment, coinbase_1]);
+        assert_eq!(transactions.num_coinbase_transactions(), 2);
+        assert!(!transactions.has_coinbase());
+    }
+
+    #[test]
+    fn test_split_coinbase() {
+        let rng = &mut TestRng::default();
+
+        let coinbase_0 = test_helpers::sample_coinbase_transaction(rng);
+        let (_, coinbase_1) = crate::block::test_helpers::sample_block_and_transaction(rng);
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+
+        // Ensure the coinbase is separated from the remaining transactions.
+        let transactions = Transactions::<CurrentNetwork>::from(&[deployment.clone(), coinbase_0.clone()]);
+        let (coinbase, remaining) = transactions.split_coinbase().unwrap();
+        assert_eq!(coinbase, &coinbase_0);
+        assert_eq!(remaining, vec![&deployment]);
+
+        // Ensure a missing coinbase is rejected.
+        let transactions = Transactions::<CurrentNetwork>::from_iter([deployment.clone()]);
+        assert!(matches!(transactions.split_coinbase(), Err(TransactionsError::MissingCoinbase)));
+
+        // Ensure multiple coinbase transactions are rejected.
+        let transactions = Transactions::<CurrentNetwork>::from(&[coinbase_0, deployment, coinbase_1]);
+        assert!(matches!(
+            transactions.split_coinbase(),
+            Err(TransactionsError::UnexpectedCoinbaseCount { expected: 1, found: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_push_coinbase() {
+        let rng = &mut TestRng::default();
+
+        let coinbase_0 = test_helpers::sample_coinbase_transaction(rng);
+        let (_, coinbase_1) = crate::block::test_helpers::sample_block_and_transaction(rng);
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+
+        // Ensure a non-coinbase transaction is rejected.
+        let mut transactions = Transactions::<CurrentNetwork>::from(&[]);
+        assert!(transactions.push_coinbase(deployment.clone()).is_err());
+        assert!(transactions.is_empty());
+
+        // Ensure the coinbase transaction is appended, and the root is recomputed.
+        transactions.push(deployment.clone()).unwrap();
+        let root = transactions.to_root().unwrap();
+        transactions.push_coinbase(coinbase_0.clone()).unwrap();
+        assert_eq!(transactions.coinbase_transaction(), Some(&coinbase_0));
+        assert_ne!(transactions.to_root().unwrap(), root);
+        assert_eq!(
+            transactions.to_root().unwrap(),
+            Transactions::from(&[deployment.clone(), coinbase_0]).to_root().unwrap()
+        );
+
+        // Ensure a second coinbase transaction is rejected.
+        assert!(transactions.push_coinbase(coinbase_1.clone()).is_err());
+        assert!(transactions.push(coinbase_1).is_err());
+        // Ensure a duplicate transaction is rejected.
+        assert!(transactions.push(deployment).is_err());
+        assert_eq!(transactions.len(), 2);
+    }
+
+    #[test]
+    fn test_merge() {
+        let rng = &mut TestRng::default();
+
+        let coinbase_0 = test_helpers::sample_coinbase_transaction(rng);
+        let (_, coinbase_1) = crate::block::test_helpers::sample_block_and_transaction(rng);
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+
+        // Ensure disjoint transactions are merged in order.
+        let merged = Transactions::<CurrentNetwork>::from_iter([deployment.clone()])
+            .merge(Transactions::from_iter([coinbase_0.clone()]))
+            .unwrap();
+        assert_eq!(merged, Transactions::from(&[deployment.clone(), coinbase_0.clone()]));
+
+        // Ensure a merge without a coinbase is rejected.
+        let merged = Transactions::<CurrentNetwork>::from_iter([deployment.clone()]).merge(Transactions::from(&[]));
+        assert!(matches!(merged, Err(TransactionsError::MissingCoinbase)));
+
+        // Ensure a merge with two coinbase transactions is rejected.
+        let merged = Transactions::<CurrentNetwork>::from_iter([coinbase_0.clone()])
+            .merge(Transactions::from_iter([coinbase_1]));
+        assert!(matches!(merged, Err(TransactionsError::UnexpectedCoinbaseCount { expected: 1, found: 2 })));
+
+        // Ensure a merge with a duplicate commitment across the sets is rejected.
+        let duplicate = test_helpers::sample_duplicate_transaction(rng);
+        let commitment = *coinbase_0.commitments().next().unwrap();
+        let merged =
+            Transactions::<CurrentNetwork>::from_iter([coinbase_0]).merge(Transactions::from_iter([duplicate]));
+        assert!(matches!(merged, Err(TransactionsError::DuplicateCommitment(candidate)) if candidate == commitment));
+    }
+
+    #[test]
+    fn test_to_transaction_fees() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a coinbase-only block has zero fees.
+        let transactions = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        assert_eq!(transactions.to_transaction_fees().unwrap(), 0);
+
+        // Ensure empty transactions have zero fees.
+        assert_eq!(Transactions::<CurrentNetwork>::from(&[]).to_transaction_fees().unwrap(), 0);
+
+        // Ensure the fees of the remaining transactions are summed.
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let expected = deployment.fee().unwrap();
+        let transactions = Transactions::from(&[transactions.iter().next().unwrap().clone(), deployment]);
+        assert_eq!(transactions.to_transaction_fees().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_transaction_fees() {
+        let rng = &mut TestRng::default();
+
+        let coinbase = test_helpers::sample_coinbase_transaction(rng);
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let execution = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let transactions = Transactions::<CurrentNetwork>::from(&[coinbase, deployment.clone(), execution.clone()]);
+
+        // Ensure the coinbase is skipped.
+        let fees = transactions.transaction_fees().unwrap();
+        assert_eq!(fees, vec![
+            (deployment.id(), deployment.fee().unwrap()),
+            (execution.id(), execution.fee().unwrap())
+        ]);
+        // Ensure the fees sum to the transaction fees.
+        assert_eq!(fees.iter().map(|(_, fee)| fee).sum::<i64>(), transactions.to_transaction_fees().unwrap());
+
+        // Ensure empty transactions have no fees.
+        assert!(Transactions::<CurrentNetwork>::from(&[]).transaction_fees().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_checked_sum() {
+        // Ensure the sum is computed.
+        assert_eq!(checked_sum([Ok(1), Ok(-3), Ok(5)].into_iter()).unwrap(), 3);
+        assert_eq!(checked_sum(std::iter::empty()).unwrap(), 0);
+        // Ensure an overflow is an error instead of a wrapped value.
+        assert!(checked_sum([Ok(i64::MAX), Ok(1)].into_iter()).is_err());
+        assert!(checked_sum([Ok(i64::MIN), Ok(-1)].into_iter()).is_err());
+        // Ensure an error is propagated.
+        assert!(checked_sum([Ok(1), Err(anyhow!("Invalid fee"))].into_iter()).is_err());
+    }
+
+    #[test]
+    fn test_split_fees() {
+        // Ensure a full split gives everything to the validator.
+        assert_eq!(split_fees(1_000, 10_000).unwrap(), (1_000, 0));
+        // Ensure an empty split gives everything to the remainder.
+        assert_eq!(split_fees(1_000, 0).unwrap(), (0, 1_000));
+        // Ensure a non-divisible split rounds the validator share down.
+        assert_eq!(split_fees(7, 5_000).unwrap(), (3, 4));
+        assert_eq!(split_fees(i64::MAX, 9_999).unwrap().0, (i64::MAX as u128 * 9_999 / 10_000) as i64);
+        // Ensure invalid inputs are rejected.
+        assert!(split_fees(-1, 5_000).is_err());
+        assert!(split_fees(1_000, 10_001).is_err());
+    }
+
+    #[test]
+    fn test_to_net_value_balance() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the net value balance includes the coinbase.
+        let transactions = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        let coinbase = transactions.coinbase_transaction().unwrap();
+        assert!(coinbase.fee().unwrap().is_negative());
+        assert_eq!(transactions.to_net_value_balance().unwrap(), coinbase.fee().unwrap());
+    }
+
+    #[test]
+    fn test_get_transaction() {
+        let rng = &mut TestRng::default();
+
+        let transaction_0 = test_helpers::sample_coinbase_transaction(rng);
+        let transaction_1 = crate::vm::test_helpers::sample_deployment_transaction(rng);
+
+        // Ensure a present transaction is found.
+        let transactions = Transactions::<CurrentNetwork>::from_iter([transaction_0.clone()]);
+        assert!(transactions.contains_transaction(&transaction_0.id()));
+        assert_eq!(transactions.get_transaction(&transaction_0.id()), Some(&transaction_0));
+
+        // Ensure an absent transaction is not found.
+        assert!(!transactions.contains_transaction(&transaction_1.id()));
+        assert_eq!(transactions.get_transaction(&transaction_1.id()), None);
+
+        // Ensure a duplicate transaction ID is stored once.
+        let transactions = Transactions::from(&[transaction_0.clone(), transaction_1, transaction_0.clone()]);
+        assert_eq!(transactions.len(), 2);
+        assert!(transactions.contains_transaction(&transaction_0.id()));
+        assert_eq!(transactions.get_transaction(&transaction_0.id()), Some(&transaction_0));
+    }
+
+    #[test]
+    fn test_decryptable_records() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block and its private key.
+        let block = crate::vm::test_helpers::sample_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+
+        // Ensure the genesis records are decryptable by the genesis view key.
+        let records = block.transactions().decryptable_records(&view_key).unwrap();
+        assert_eq!(records.len(), block.transactions().iter().flat_map(Transaction::records).count());
+        for (transaction_id, record) in records {
+            assert!(block.transactions().contains_key(&transaction_id));
+            assert_eq!(**record.owner(), Address::try_from(&private_key).unwrap());
+        }
+
+        // Ensure no records are decryptable by an unrelated view key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        assert!(block.transactions().decryptable_records(&view_key).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_gossip_fingerprint() {
+        let rng = &mut TestRng::default();
+
+        let transaction_0 = test_helpers::sample_coinbase_transaction(rng);
+        let transaction_1 = crate::vm::test_helpers::sample_deployment_transaction(rng);
+
+        // Ensure the fingerprint is stable for identical ordered sets.
+        let transactions = Transactions::<CurrentNetwork>::from(&[transaction_0.clone(), transaction_1.clone()]);
+        let expected = transactions.gossip_fingerprint().unwrap();
+        assert_eq!(expected, transactions.clone().gossip_fingerprint().unwrap());
+        assert_eq!(expected, transactions.iter().collect::<Transactions<_>>().gossip_fingerprint().unwrap());
+
+        // Ensure the fingerprint depends on the order and the membership.
+        let reordered = Transactions::<CurrentNetwork>::from(&[transaction_1, transaction_0.clone()]);
+        assert_ne!(expected, reordered.gossip_fingerprint().unwrap());
+        let subset = Transactions::<CurrentNetwork>::from(&[transaction_0]);
+        assert_ne!(expected, subset.gossip_fingerprint().unwrap());
+    }
+
+    #[test]
+    fn test_find_no_effect_transactions() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the coinbase and the deployment have effects.
+        let transaction_0 = test_helpers::sample_coinbase_transaction(rng);
+        let transaction_1 = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let transactions = Transactions::<CurrentNetwork>::from(&[transaction_0.clone(), transaction_1]);
+        assert!(transactions.find_no_effect_transactions().is_empty());
+
+        // Initialize a program with a function that has no inputs and no outputs.
+        let program = crate::Program::<CurrentNetwork>::from_str(
+            r"
+program noop.aleo;
+
+function noop:",
+        )
+        .unwrap();
+
+        // Execute the function.
+        let process = crate::process::test_helpers::sample_process(&program);
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let authorization = process
+            .authorize::<circuit::network::AleoV0, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str("noop").unwrap(),
+                &[],
+                rng,
+            )
+            .unwrap();
+        let (_, execution, _) = process.execute::<circuit::network::AleoV0, _>(authorization, rng).unwrap();
+
+        // Prepare the execution with an additional fee.
+        let fee = crate::vm::test_helpers::sample_fee();
+        let with_fee = Transaction::from_execution(execution.clone(), Some(fee.clone())).unwrap();
+        // Prepare the execution with the fee transition as part of the execution.
+        let transitions = execution.transitions().chain([fee.transition()]).cloned();
+        let with_fee_transition = Transaction::from_execution(
+            Execution::from(transitions, execution.global_state_root(), None).unwrap(),
+            None,
+        )
+        .unwrap();
+        // Prepare an execution of only the fee transition.
+        let fee_only = Transaction::from_execution(
+            Execution::from([fee.transition().clone()].into_iter(), fee.global_state_root(), None).unwrap(),
+            None,
+        )
+        .unwrap();
+
+        // Ensure the executions without effects are flagged, but not the fee.
+        let transactions = Transactions::<CurrentNetwork>::from(&[
+            transaction_0,
+            with_fee.clone(),
+            with_fee_transition.clone(),
+            fee_only,
+        ]);
+        assert_eq!(transactions.find_no_effect_transactions(), vec![with_fee.id(), with_fee_transition.id()]);
+    }
+}
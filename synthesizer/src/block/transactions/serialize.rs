@@ -105,6 +105,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_serde_json_is_array() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the empty transactions serialize to an empty array.
+        let empty = Transactions::<CurrentNetwork>::from(&[]);
+        assert_eq!(serde_json::to_string(&empty).unwrap(), "[]");
+        assert_eq!(empty, serde_json::from_str("[]").unwrap());
+
+        // Ensure the transactions serialize to an array of transactions.
+        let expected = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        let candidate = serde_json::to_value(&expected).unwrap();
+        let elements = candidate.as_array().unwrap();
+        assert_eq!(elements.len(), expected.len());
+        for (element, transaction) in elements.iter().zip_eq(expected.values()) {
+            assert_eq!(element, &serde_json::to_value(transaction).unwrap());
+        }
+        assert_eq!(expected, serde_json::from_value(candidate).unwrap());
+    }
+
     #[test]
     fn test_bincode() {
         let rng = &mut TestRng::default();
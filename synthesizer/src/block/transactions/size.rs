@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Transactions<N> {
+    /// Returns the number of bytes in the serialized transactions, without allocating a buffer.
+    /// Note: If a transaction fails to serialize, `usize::MAX` is returned, so the transactions never fit a limit.
+    pub fn size_in_bytes(&self) -> usize {
+        serialized_size(self)
+    }
+
+    /// Returns `true` if the serialized transactions are at most `max_bytes` bytes.
+    pub fn fits_within(&self, max_bytes: usize) -> bool {
+        self.size_in_bytes() <= max_bytes
+    }
+
+    /// Returns an estimate of the heap memory occupied by the transactions, in bytes.
+    /// Note: The serialized size of each transaction is used as a proxy for its in-memory footprint.
+    pub fn estimated_heap_bytes(&self) -> usize {
+        // Compute the size of the entries allocated by the map.
+        let entry_size = core::mem::size_of::<N::TransactionID>()
+            + core::mem::size_of::<Transaction<N>>()
+            + core::mem::size_of::<usize>();
+        let map_bytes = self.transactions.capacity().saturating_mul(entry_size);
+        // Compute the serialized size of each transaction.
+        self.values().fold(map_bytes, |cumulative, transaction| cumulative.saturating_add(serialized_size(transaction)))
+    }
+
+    /// Returns the total size of the proofs in the transactions, in bytes.
+    /// Note: This includes the transition proofs, and the inclusion proofs of the executions and fees.
+    pub fn total_proof_size(&self) -> usize {
+        let mut total_proof_size = 0usize;
+        for transaction in self.values() {
+            // Retrieve the inclusion proofs.
+            let inclusion_proofs = match transaction {
+                Transaction::Deploy(_, _, fee) => [fee.inclusion_proof(), None],
+                Transaction::Execute(_, execution, fee) => {
+                    [execution.inclusion_proof(), fee.as_ref().and_then(|fee| fee.inclusion_proof())]
+                }
+            };
+            // Sum the sizes of the transition proofs and inclusion proofs.
+            for proof in transaction.transitions().map(Transition::proof).chain(inclusion_proofs.into_iter().flatten())
+            {
+                total_proof_size = total_proof_size.saturating_add(serialized_size(proof));
+            }
+        }
+        total_proof_size
+    }
+
+    /// Returns the number of serialized bytes that are not proofs.
+    pub fn metadata_bytes(&self) -> usize {
+        self.size_in_bytes().saturating_sub(self.total_proof_size())
+    }
+
+    /// Returns the fraction of the serialized bytes that are proofs.
+    pub fn proof_byte_ratio(&self) -> f64 {
+        // Note: The serialized size is never zero, as it includes the version and the number of transactions.
+        self.total_proof_size() as f64 / self.size_in_bytes() as f64
+    }
+}
+
+/// Returns the number of bytes in the serialized form of the given value, without allocating a buffer.
+/// Note: Writing to the counter cannot fail, so this only fails if the value itself is malformed,
+/// in which case `usize::MAX` is returned.
+pub(super) fn serialized_size<T: ToBytes>(value: &T) -> usize {
+    /// A writer that only counts the bytes written to it.
+    struct ByteCounter(usize);
+
+    impl std::io::Write for ByteCounter {
+        fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+            self.0 += buffer.len();
+            Ok(buffer.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut counter = ByteCounter(0);
+    match value.write_le(&mut counter) {
+        Ok(()) => counter.0,
+        Err(_) => usize::MAX,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialized_size() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the serialized size matches the length of the serialized bytes.
+        let transactions = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        assert_eq!(serialized_size(&transactions), transactions.to_bytes_le().unwrap().len());
+        for transaction in transactions.values() {
+            assert_eq!(serialized_size(transaction), transaction.to_bytes_le().unwrap().len());
+        }
+    }
+
+    #[test]
+    fn test_size_in_bytes() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the size matches the length of the serialized bytes.
+        let transactions = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        let size = transactions.to_bytes_le().unwrap().len();
+        assert_eq!(transactions.size_in_bytes(), size);
+
+        // Ensure the size limit is inclusive.
+        assert!(transactions.fits_within(size));
+        assert!(transactions.fits_within(usize::MAX));
+        assert!(!transactions.fits_within(size - 1));
+
+        // Ensure empty transactions only contain the version and the number of transactions.
+        let transactions = Transactions::<console::network::Testnet3>::from(&[]);
+        assert_eq!(transactions.size_in_bytes(), transactions.to_bytes_le().unwrap().len());
+    }
+
+    #[test]
+    fn test_estimated_heap_bytes() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the estimate covers at least the serialized transactions.
+        let transactions = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        let serialized_bytes = transactions.values().map(|transaction| transaction.to_bytes_le().unwrap().len()).sum();
+        assert!(transactions.estimated_heap_bytes() > serialized_bytes);
+
+        // Ensure the estimate grows with the number of transactions.
+        let mut more_transactions = transactions.iter().cloned().collect::<Vec<_>>();
+        more_transactions.push(crate::vm::test_helpers::sample_deployment_transaction(rng));
+        let more_transactions = Transactions::from(&more_transactions);
+        assert!(more_transactions.estimated_heap_bytes() > transactions.estimated_heap_bytes());
+    }
+
+    #[test]
+    fn test_proof_byte_ratio() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the proof and metadata bytes partition the serialized bytes.
+        let transactions = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        let total_proof_size = transactions.total_proof_size();
+        let metadata_bytes = transactions.metadata_bytes();
+        assert!(total_proof_size > 0);
+        assert_eq!(total_proof_size + metadata_bytes, transactions.to_bytes_le().unwrap().len());
+
+        // Ensure the ratio is the fraction of proof bytes.
+        let ratio = transactions.proof_byte_ratio();
+        assert!(ratio > 0.0 && ratio < 1.0);
+        assert_eq!(ratio, total_proof_size as f64 / (total_proof_size + metadata_bytes) as f64);
+
+        // Ensure empty transactions contain no proofs.
+        let transactions = Transactions::<console::network::Testnet3>::from(&[]);
+        assert_eq!(transactions.total_proof_size(), 0);
+        assert_eq!(transactions.proof_byte_ratio(), 0.0);
+    }
+}
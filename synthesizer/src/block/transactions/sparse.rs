@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A leaf of the transactions tree, where unrevealed transactions are replaced by their IDs.
+#[derive(Clone, PartialEq, Eq)]
+pub enum SparseLeaf<N: Network> {
+    /// A revealed transaction.
+    Revealed(Box<Transaction<N>>),
+    /// A placeholder for an unrevealed transaction, which only discloses the transaction ID.
+    Placeholder(N::TransactionID),
+}
+
+impl<N: Network> SparseLeaf<N> {
+    /// Returns the transaction ID of the leaf.
+    pub const fn id(&self) -> N::TransactionID {
+        match self {
+            Self::Revealed(transaction) => transaction.id(),
+            Self::Placeholder(id) => *id,
+        }
+    }
+}
+
+impl<N: Network> Transactions<N> {
+    /// Returns the transactions root, along with the leaves of the tree,
+    /// where only the transactions at the given indices are revealed.
+    pub fn to_transactions_root_sparse(&self, reveal: &[usize]) -> Result<(Field<N>, Vec<SparseLeaf<N>>)> {
+        // Ensure the revealed indices are in range.
+        if let Some(index) = reveal.iter().find(|index| **index >= self.transactions.len()) {
+            bail!("Cannot reveal transaction {index}, as the block contains {} transactions", self.transactions.len())
+        }
+        // Prepare the leaves.
+        let leaves = self
+            .transactions
+            .values()
+            .enumerate()
+            .map(|(index, transaction)| match reveal.contains(&index) {
+                true => SparseLeaf::Revealed(Box::new(transaction.clone())),
+                false => SparseLeaf::Placeholder(transaction.id()),
+            })
+            .collect();
+        Ok((self.to_root()?, leaves))
+    }
+
+    /// Ensures the given leaves correspond to the given transactions root,
+    /// and that each revealed transaction matches its transaction ID.
+    pub fn verify_transactions_root_sparse(root: &Field<N>, leaves: &[SparseLeaf<N>]) -> Result<()> {
+        // Ensure the number of leaves is within the allowed range.
+        ensure!(
+            leaves.len() <= Self::MAX_TRANSACTIONS,
+            "Block cannot exceed {} transactions, found {}",
+            Self::MAX_TRANSACTIONS,
+            leaves.len()
+        );
+        // Ensure each revealed transaction commits to its transaction ID.
+        for leaf in leaves {
+            if let SparseLeaf::Revealed(transaction) = leaf {
+                ensure!(*transaction.id() == transaction.to_root()?, "Incorrect transaction ID ({})", transaction.id());
+            }
+        }
+        // Compute the transactions root from the transaction IDs.
+        let leaves = leaves.iter().map(|leaf| leaf.id().to_bits_le()).collect::<Vec<_>>();
+        let candidate_root = *N::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&leaves)?.root();
+        // Ensure the transactions root matches.
+        ensure!(candidate_root == *root, "The sparse transactions root does not match the given root");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transactions_root_sparse() {
+        let rng = &mut TestRng::default();
+
+        let transaction_0 = crate::block::transactions::test_helpers::sample_coinbase_transaction(rng);
+        let transaction_1 = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let transactions = Transactions::from(&[transaction_0.clone(), transaction_1.clone()]);
+        let expected_root = transactions.to_root().unwrap();
+
+        for reveal in [vec![], vec![0], vec![1], vec![0, 1]] {
+            // Ensure the sparse root matches the transactions root.
+            let (root, leaves) = transactions.to_transactions_root_sparse(&reveal).unwrap();
+            assert_eq!(root, expected_root);
+            assert_eq!(leaves.len(), 2);
+            for (index, leaf) in leaves.iter().enumerate() {
+                assert_eq!(reveal.contains(&index), matches!(leaf, SparseLeaf::Revealed(..)));
+            }
+            // Ensure the sparse leaves verify against the root.
+            assert!(Transactions::verify_transactions_root_sparse(&root, &leaves).is_ok());
+        }
+
+        // Ensure an out-of-range index is rejected.
+        assert!(transactions.to_transactions_root_sparse(&[2]).is_err());
+
+        // Ensure reordered leaves are rejected.
+        let leaves =
+            vec![SparseLeaf::Placeholder(transaction_1.id()), SparseLeaf::Revealed(Box::new(transaction_0.clone()))];
+        assert!(Transactions::verify_transactions_root_sparse(&expected_root, &leaves).is_err());
+
+        // Ensure a different root is rejected.
+        let (_, leaves) = transactions.to_transactions_root_sparse(&[0]).unwrap();
+        let other_root = Transactions::from(&[transaction_0]).to_root().unwrap();
+        assert!(Transactions::verify_transactions_root_sparse(&other_root, &leaves).is_err());
+    }
+}
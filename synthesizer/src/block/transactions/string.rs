@@ -16,6 +16,52 @@
 
 use super::*;
 
+/// The number of bytes of the transactions root that are included in the summary.
+const SUMMARY_ROOT_PREFIX_SIZE: usize = 4;
+
+impl<N: Network> Transactions<N> {
+    /// Returns the transactions as a list of hex-encoded strings, one per transaction.
+    pub fn serialize_as_strs(&self) -> Result<Vec<String>> {
+        self.values().map(|transaction| Ok(hex::encode(transaction.to_bytes_le()?))).collect()
+    }
+
+    /// Initializes the transactions from a list of hex-encoded strings, one per transaction.
+    /// If a string is malformed, the error identifies its index.
+    pub fn deserialize_from_strs(strs: &[String]) -> Result<Self> {
+        let transactions = strs
+            .iter()
+            .enumerate()
+            .map(|(index, string)| match hex::decode(string) {
+                Ok(bytes) => Transaction::from_bytes_le(&bytes)
+                    .map_err(|error| error.context(format!("Failed to deserialize transaction {index}"))),
+                Err(error) => bail!("Failed to decode transaction {index}: {error}"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::from(&transactions))
+    }
+
+    /// Returns a compact, one-line summary of the transactions, for logging.
+    /// Note: Unlike `Display`, the summary is not a serialization, and cannot be parsed back.
+    pub fn summary(&self) -> String {
+        // Compute the total fees, for all transactions that are not a coinbase.
+        let fees = match self.to_transaction_fees() {
+            Ok(fees) => fees.to_string(),
+            Err(_) => "invalid".to_string(),
+        };
+        // Compute the hex-encoded prefix of the transactions root.
+        let root = match self.to_root().and_then(|root| root.to_bytes_le()) {
+            Ok(bytes) => hex::encode(&bytes[..SUMMARY_ROOT_PREFIX_SIZE]),
+            Err(_) => "invalid".to_string(),
+        };
+        format!(
+            "transactions={} transitions={} coinbase={} fees={fees} root={root}",
+            self.num_transactions(),
+            self.num_transitions(),
+            self.has_coinbase(),
+        )
+    }
+}
+
 impl<N: Network> FromStr for Transactions<N> {
     type Err = Error;
 
@@ -38,3 +84,52 @@ impl<N: Network> Display for Transactions<N> {
         write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serialize_as_strs() {
+        let rng = &mut TestRng::default();
+
+        // Serialize the transactions, and reparse them.
+        let expected = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        let strs = expected.serialize_as_strs().unwrap();
+        assert_eq!(strs.len(), expected.len());
+        assert_eq!(expected, Transactions::deserialize_from_strs(&strs).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_from_strs_malformed() {
+        // Ensure no strings yields no transactions.
+        assert!(Transactions::<CurrentNetwork>::deserialize_from_strs(&[]).unwrap().is_empty());
+
+        // Ensure a malformed string is identified by its index.
+        let error = Transactions::<CurrentNetwork>::deserialize_from_strs(&["zz".to_string()]).unwrap_err();
+        assert!(error.to_string().contains("transaction 0"), "{error}");
+    }
+
+    #[test]
+    fn test_summary() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the summary contains the counts and the root prefix of the transactions.
+        let transactions = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        let summary = transactions.summary();
+        assert!(summary.starts_with(&format!("transactions={} ", transactions.len())), "{summary}");
+        assert!(summary.contains(&format!("transitions={} ", transactions.num_transitions())), "{summary}");
+        assert!(summary.contains("coinbase=true "), "{summary}");
+
+        // Ensure the root prefix is non-empty, and matches the transactions root.
+        let root = summary.split("root=").nth(1).unwrap();
+        assert!(!root.is_empty());
+        assert!(hex::encode(transactions.to_root().unwrap().to_bytes_le().unwrap()).starts_with(root), "{summary}");
+
+        // Ensure the summary fits on one line.
+        assert!(!summary.contains('\n'));
+    }
+}
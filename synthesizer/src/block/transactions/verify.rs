@@ -0,0 +1,499 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::program::Program;
+
+use std::collections::HashSet;
+
+impl<N: Network> Transactions<N> {
+    /// Returns `true` if the transactions are valid.
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Ensures the transactions are valid, returning the reason if they are not.
+    /// Note: This method does not verify the transaction proofs, which is performed by `VM::verify`.
+    pub fn validate(&self) -> Result<(), TransactionsError<N>> {
+        self.validate_with_max_transactions(Self::MAX_TRANSACTIONS)
+    }
+
+    /// Ensures the transactions are valid, and that there are at most `max_transactions` transactions.
+    /// Note: The number of transactions is checked first, before any per-transaction check.
+    fn validate_with_max_transactions(&self, max_transactions: usize) -> Result<(), TransactionsError<N>> {
+        // Ensure the number of transactions is within the allowed range.
+        check_num_transactions_within(self.transactions.len(), max_transactions)?;
+        // Ensure each transaction ID is correct.
+        if let Some(transaction) = self.values().find(|transaction| !has_valid_id(transaction)) {
+            return Err(TransactionsError::InvalidTransaction(transaction.id()));
+        }
+        // Ensure there are no duplicate serial numbers.
+        if let Some(serial_number) = find_first_duplicate(self.serial_numbers()) {
+            return Err(TransactionsError::DuplicateSerialNumber(*serial_number));
+        }
+        // Ensure there are no duplicate commitments.
+        if let Some(commitment) = find_first_duplicate(self.commitments()) {
+            return Err(TransactionsError::DuplicateCommitment(*commitment));
+        }
+        // Ensure the number of coinbase transactions is within the allowed range.
+        let num_coinbase = self.num_coinbase_transactions();
+        if num_coinbase > Self::MAX_COINBASE_TRANSACTIONS {
+            return Err(TransactionsError::UnexpectedCoinbaseCount {
+                expected: Self::MAX_COINBASE_TRANSACTIONS,
+                found: num_coinbase,
+            });
+        }
+        Ok(())
+    }
+
+    /// Initializes the transactions from the given list, ensuring the transactions are valid.
+    /// Note: This method performs the same checks as `validate`.
+    pub fn from_validated(transactions: &[Transaction<N>]) -> Result<Self, TransactionsError<N>> {
+        // Construct the transactions.
+        let transactions = Self::from(transactions);
+        // Ensure the transactions are valid.
+        transactions.validate()?;
+        Ok(transactions)
+    }
+
+    /// Removes every transaction with an incorrect transaction ID, returning the number of transactions removed.
+    pub fn retain_valid(&mut self) -> usize {
+        self.drain_invalid().len()
+    }
+
+    /// Removes and returns every transaction with an incorrect transaction ID, in order.
+    pub fn drain_invalid(&mut self) -> Vec<Transaction<N>> {
+        // Partition the valid transactions from the invalid transactions.
+        let (valid, invalid): (IndexMap<_, _>, IndexMap<_, _>) =
+            core::mem::take(&mut self.transactions).into_iter().partition(|(_, transaction)| has_valid_id(transaction));
+        // Retain the valid transactions.
+        self.transactions = valid;
+        // Clear the cached transactions root, if any transactions were removed.
+        if !invalid.is_empty() {
+            self.clear_cache();
+        }
+        invalid.into_values().collect()
+    }
+
+    /// Ensures the given number of transactions is at most `MAX_TRANSACTIONS`.
+    pub(super) fn check_num_transactions(num_transactions: usize) -> Result<(), TransactionsError<N>> {
+        check_num_transactions_within(num_transactions, Self::MAX_TRANSACTIONS)
+    }
+
+    /// Ensures each transaction contains at most `max_transitions` transitions.
+    pub fn verify_transition_limits(&self, max_transitions: usize) -> Result<(), TransactionsError<N>> {
+        // Find the first transaction that exceeds the transition limit.
+        for transaction in self.values() {
+            let num_transitions = transaction.num_transitions();
+            if num_transitions > max_transitions {
+                return Err(TransactionsError::TooManyTransitions {
+                    transaction_id: transaction.id(),
+                    num_transitions,
+                    max_transitions,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the fee transition of each transaction is well-formed.
+    /// Note: This method does not verify the fee proofs, which is performed by `VM::verify`.
+    pub fn verify_fee_transitions(&self) -> Result<(), TransactionsError<N>> {
+        // Prepare the program ID and function name of the fee.
+        let fee_function = fee_function::<N>();
+
+        for transaction in self.values() {
+            // Retrieve the fee transition, if one exists.
+            let transition = match transaction.fee_transition() {
+                Some(transition) => transition,
+                None => continue,
+            };
+            // Prepare the error for the fee transition.
+            let invalid = |reason: &str| TransactionsError::InvalidFeeTransition {
+                transaction_id: transaction.id(),
+                transition_id: *transition.id(),
+                reason: reason.to_string(),
+            };
+            // Ensure the fee calls 'credits.aleo/fee'.
+            match &fee_function {
+                Ok((program_id, function_name))
+                    if transition.program_id() == program_id && transition.function_name() == function_name => {}
+                Ok(_) => return Err(invalid("the fee must call 'credits.aleo/fee'")),
+                Err(error) => return Err(invalid(&error.to_string())),
+            }
+            // Ensure the transition ID is correct.
+            match transition.to_root() {
+                Ok(root) if **transition.id() == root => (),
+                Ok(_) => return Err(invalid("the transition ID is incorrect")),
+                Err(error) => return Err(invalid(&error.to_string())),
+            }
+            // Ensure the fee is not negative.
+            if transition.fee().is_negative() {
+                return Err(invalid("the fee must not be negative"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the coinbase records decrypt under the given view key, and are owned by an allowed address.
+    pub fn verify_coinbase_recipient(
+        &self,
+        view_key: &ViewKey<N>,
+        allowed: &HashSet<Address<N>>,
+    ) -> Result<(), TransactionsError<N>> {
+        // Retrieve the coinbase transaction.
+        let transaction = self.coinbase_transaction().ok_or(TransactionsError::MissingCoinbase)?;
+        let transaction_id = transaction.id();
+
+        // Ensure each coinbase record is owned by an allowed address.
+        for transition in transaction.transitions() {
+            if !Program::is_coinbase(transition.program_id(), transition.function_name()) {
+                continue;
+            }
+            for (commitment, record) in transition.records() {
+                // Decrypt the record.
+                let record = record.decrypt(view_key).map_err(|_| TransactionsError::UndecryptableCoinbaseRecord {
+                    transaction_id,
+                    commitment: *commitment,
+                })?;
+                // Ensure the recipient is allowed.
+                let recipient = **record.owner();
+                if !allowed.contains(&recipient) {
+                    return Err(TransactionsError::UnexpectedCoinbaseRecipient { transaction_id, recipient });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the first element of the given iterator that was already seen, if one exists.
+/// Note: With the `parallel` feature, the elements are first checked concurrently, and the serial scan
+/// only runs to identify the first duplicate if one exists. Both paths return the same element.
+fn find_first_duplicate<'a, N: Network>(elements: impl Iterator<Item = &'a Field<N>>) -> Option<&'a Field<N>> {
+    #[cfg(feature = "parallel")]
+    {
+        let elements = elements.collect::<Vec<_>>();
+        par_find_duplicate(&elements)?;
+        find_duplicate(elements)
+    }
+    #[cfg(not(feature = "parallel"))]
+    find_duplicate(elements)
+}
+
+/// Returns an element of the given slice that occurs more than once, if one exists,
+/// by inserting the elements into a sharded concurrent set in parallel.
+/// Note: If there are several duplicates, the returned element is not deterministic.
+#[cfg(feature = "parallel")]
+fn par_find_duplicate<T: Eq + core::hash::Hash + Sync>(elements: &[T]) -> Option<&T> {
+    use core::hash::BuildHasher;
+    use std::{collections::hash_map::RandomState, sync::Mutex};
+
+    /// The number of shards in the concurrent set.
+    const NUM_SHARDS: usize = 64;
+
+    // Initialize the shards, which share a hasher to select the shard of each element.
+    let state = RandomState::new();
+    let shards = (0..NUM_SHARDS).map(|_| Mutex::new(HashSet::new())).collect::<Vec<_>>();
+    // Find an element that is already in its shard.
+    elements.par_iter().find_any(|element| {
+        let shard = &shards[state.hash_one(element) as usize % NUM_SHARDS];
+        !shard.lock().unwrap_or_else(|error| error.into_inner()).insert(*element)
+    })
+}
+
+/// Ensures the given number of transactions is at most `max_transactions`.
+fn check_num_transactions_within<N: Network>(
+    num_transactions: usize,
+    max_transactions: usize,
+) -> Result<(), TransactionsError<N>> {
+    match num_transactions <= max_transactions {
+        true => Ok(()),
+        false => Err(TransactionsError::TooManyTransactions { num_transactions, max_transactions }),
+    }
+}
+
+/// Returns `true` if the transaction ID matches the transaction root.
+pub(super) fn has_valid_id<N: Network>(transaction: &Transaction<N>) -> bool {
+    matches!(transaction.to_root(), Ok(root) if *transaction.id() == root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::Fee;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_validate() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the genesis transactions are valid.
+        let transactions = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        assert!(transactions.validate().is_ok());
+        assert!(transactions.is_valid());
+
+        // Ensure a transaction with an incorrect ID is reported.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let incorrect_id = crate::vm::test_helpers::sample_deployment_transaction(rng).id();
+        let tampered = match transaction {
+            Transaction::Execute(_, execution, fee) => Transaction::Execute(incorrect_id, execution, fee),
+            _ => unreachable!("Expected an execution transaction"),
+        };
+        let transactions = Transactions::from(&[tampered]);
+        assert!(
+            matches!(transactions.validate(), Err(TransactionsError::InvalidTransaction(id)) if id == incorrect_id)
+        );
+        assert!(!transactions.is_valid());
+
+        // Ensure multiple coinbase transactions are reported.
+        let coinbase_0 = crate::block::transactions::test_helpers::sample_coinbase_transaction(rng);
+        let (_, coinbase_1) = crate::block::test_helpers::sample_block_and_transaction(rng);
+        assert_ne!(coinbase_0.id(), coinbase_1.id());
+        let transactions = Transactions::from(&[coinbase_0, coinbase_1]);
+        assert!(matches!(
+            transactions.validate(),
+            Err(TransactionsError::UnexpectedCoinbaseCount { expected: 1, found: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_from_validated() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a valid list is accepted.
+        let expected = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        let transactions = expected.iter().cloned().collect::<Vec<_>>();
+        assert_eq!(Transactions::from_validated(&transactions).unwrap(), expected);
+
+        // Construct a second transaction that reuses the coinbase execution, under an additional fee.
+        let coinbase = transactions[0].clone();
+        let duplicate = crate::block::transactions::test_helpers::sample_duplicate_transaction(rng);
+        assert_ne!(coinbase.id(), duplicate.id());
+
+        // Ensure a list with duplicate commitments is rejected.
+        let commitment = *coinbase.commitments().next().unwrap();
+        assert!(matches!(
+            Transactions::from_validated(&[coinbase, duplicate]),
+            Err(TransactionsError::DuplicateCommitment(candidate)) if candidate == commitment
+        ));
+    }
+
+    #[test]
+    fn test_drain_invalid() {
+        let rng = &mut TestRng::default();
+
+        let coinbase = crate::block::transactions::test_helpers::sample_coinbase_transaction(rng);
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+
+        // Construct a transaction with an incorrect ID.
+        let tampered = match crate::vm::test_helpers::sample_execution_transaction(rng) {
+            Transaction::Execute(_, execution, fee) => Transaction::Execute(deployment.id(), execution, fee),
+            _ => unreachable!("Expected an execution transaction"),
+        };
+
+        // Ensure only the invalid transaction is removed.
+        let mut transactions = Transactions::<CurrentNetwork>::from(&[coinbase.clone(), tampered.clone()]);
+        let root = transactions.to_root().unwrap();
+        assert_eq!(transactions.drain_invalid(), vec![tampered.clone()]);
+        assert_eq!(transactions, Transactions::from_iter([coinbase.clone()]));
+        assert_ne!(transactions.to_root().unwrap(), root);
+        assert!(transactions.is_valid());
+
+        // Ensure the number of removed transactions is returned.
+        let mut transactions = Transactions::<CurrentNetwork>::from(&[tampered, coinbase.clone(), deployment.clone()]);
+        assert_eq!(transactions.retain_valid(), 1);
+        assert_eq!(transactions, Transactions::from(&[coinbase, deployment]));
+        assert_eq!(transactions.retain_valid(), 0);
+    }
+
+    #[test]
+    fn test_check_num_transactions() {
+        let max_transactions = Transactions::<CurrentNetwork>::MAX_TRANSACTIONS;
+
+        // Ensure the limit is inclusive.
+        assert!(Transactions::<CurrentNetwork>::check_num_transactions(0).is_ok());
+        assert!(Transactions::<CurrentNetwork>::check_num_transactions(max_transactions).is_ok());
+
+        // Ensure an over-limit block is rejected.
+        assert!(matches!(
+            Transactions::<CurrentNetwork>::check_num_transactions(max_transactions + 1),
+            Err(TransactionsError::TooManyTransactions { num_transactions, max_transactions: max })
+                if num_transactions == max_transactions + 1 && max == max_transactions
+        ));
+    }
+
+    #[test]
+    fn test_validate_too_many_transactions() {
+        let rng = &mut TestRng::default();
+
+        // Construct two transactions, one of which has an incorrect ID.
+        // Note: A reduced limit is used, as sampling more than `MAX_TRANSACTIONS` transactions is impractical.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let incorrect_id = crate::vm::test_helpers::sample_deployment_transaction(rng).id();
+        let tampered = match transaction.clone() {
+            Transaction::Execute(_, execution, fee) => Transaction::Execute(incorrect_id, execution, fee),
+            _ => unreachable!("Expected an execution transaction"),
+        };
+        let transactions = Transactions::<CurrentNetwork>::from(&[transaction, tampered]);
+
+        // Ensure an over-limit set is rejected, before the transaction IDs are checked.
+        assert!(matches!(
+            transactions.validate_with_max_transactions(1),
+            Err(TransactionsError::TooManyTransactions { num_transactions: 2, max_transactions: 1 })
+        ));
+
+        // Ensure the transaction IDs are checked, once the set is within the limit.
+        assert!(matches!(
+            transactions.validate_with_max_transactions(2),
+            Err(TransactionsError::InvalidTransaction(id)) if id == incorrect_id
+        ));
+        assert!(matches!(
+            transactions.validate(),
+            Err(TransactionsError::InvalidTransaction(id)) if id == incorrect_id
+        ));
+    }
+
+    #[test]
+    fn test_find_duplicate_large() {
+        let rng = &mut TestRng::default();
+
+        // Sample a large number of distinct commitments, as in a large synthetic block.
+        let commitments = (0..1 << 16).map(|_| Field::<CurrentNetwork>::rand(rng)).collect::<Vec<_>>();
+        assert!(find_duplicate(commitments.iter()).is_none());
+
+        // Ensure the first repeated commitment is found.
+        let mut commitments = commitments;
+        commitments.insert(10_000, commitments[100]);
+        assert_eq!(find_duplicate(commitments.iter()), Some(&commitments[100]));
+    }
+
+    #[test]
+    fn test_find_first_duplicate() {
+        let rng = &mut TestRng::default();
+
+        // Sample distinct commitments, as if from many transactions.
+        let commitments = (0..1 << 12).map(|_| Field::<CurrentNetwork>::rand(rng)).collect::<Vec<_>>();
+        assert!(find_first_duplicate(commitments.iter()).is_none());
+
+        // Ensure duplicates spread across the commitments are found, and the first one is returned.
+        let mut commitments = commitments;
+        commitments.insert(3_000, commitments[10]);
+        commitments.insert(4_000, commitments[2_000]);
+        assert_eq!(find_first_duplicate(commitments.iter()), Some(&commitments[10]));
+        assert_eq!(find_first_duplicate(commitments.iter()), find_duplicate(commitments.iter()));
+
+        // Ensure the parallel path detects the duplicates.
+        #[cfg(feature = "parallel")]
+        {
+            let duplicate = par_find_duplicate(&commitments).unwrap();
+            assert!(duplicate == &commitments[10] || duplicate == &commitments[2_000]);
+            assert!(par_find_duplicate(&commitments[..3_000]).is_none());
+        }
+    }
+
+    #[test]
+    fn test_verify_transition_limits() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis transactions.
+        let transactions = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        let num_transitions = transactions.transitions().count();
+        assert!(num_transitions > 0);
+
+        // Ensure the transactions are within the limit.
+        assert!(transactions.verify_transition_limits(num_transitions).is_ok());
+        assert!(transactions.verify_transition_limits(usize::MAX).is_ok());
+
+        // Ensure the first violator is reported.
+        let transaction = transactions.iter().next().unwrap();
+        match transactions.verify_transition_limits(0) {
+            Err(TransactionsError::TooManyTransitions { transaction_id, num_transitions, max_transitions }) => {
+                assert_eq!(transaction_id, transaction.id());
+                assert_eq!(num_transitions, transaction.num_transitions());
+                assert_eq!(max_transitions, 0);
+            }
+            result => panic!("Expected a transition limit error, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_verify_coinbase_recipient() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis transactions, which pay the coinbase to the genesis address.
+        let transactions = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let transaction_id = transactions.coinbase_transaction().unwrap().id();
+
+        // Ensure the coinbase recipient is allowed.
+        assert!(transactions.verify_coinbase_recipient(&view_key, &HashSet::from([address])).is_ok());
+
+        // Ensure an unexpected recipient is rejected.
+        match transactions.verify_coinbase_recipient(&view_key, &HashSet::new()) {
+            Err(TransactionsError::UnexpectedCoinbaseRecipient { transaction_id: id, recipient }) => {
+                assert_eq!(id, transaction_id);
+                assert_eq!(recipient, address);
+            }
+            result => panic!("Expected an unexpected coinbase recipient error, found {result:?}"),
+        }
+
+        // Ensure a foreign view key is rejected.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(transactions.verify_coinbase_recipient(&other_view_key, &HashSet::from([address])).is_err());
+
+        // Ensure transactions without a coinbase are rejected.
+        let transactions = Transactions::from(&[crate::vm::test_helpers::sample_deployment_transaction(rng)]);
+        assert!(matches!(
+            transactions.verify_coinbase_recipient(&view_key, &HashSet::from([address])),
+            Err(TransactionsError::MissingCoinbase)
+        ));
+    }
+
+    #[test]
+    fn test_verify_fee_transitions() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the coinbase has no fee transition.
+        let transactions = crate::vm::test_helpers::sample_genesis_block(rng).transactions().clone();
+        assert!(transactions.fee_transitions().unwrap().is_empty());
+        assert!(transactions.verify_fee_transitions().is_ok());
+
+        // Ensure a deployment exposes its fee transition.
+        let transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let transactions = Transactions::from_iter([transaction.clone()]);
+        let fee_transitions = transactions.fee_transitions().unwrap();
+        assert_eq!(fee_transitions.len(), 1);
+        assert_eq!(fee_transitions[0].0, transaction.id());
+        assert_eq!(&fee_transitions[0].1, transaction.fee_transition().unwrap());
+        assert!(transactions.verify_fee_transitions().is_ok());
+
+        // Ensure a fee that does not call 'credits.aleo/fee' is rejected.
+        let (block, coinbase) = crate::block::test_helpers::sample_block_and_transaction(rng);
+        assert!(block.transactions().verify_fee_transitions().is_ok());
+        let fee = Fee::from(coinbase.transitions().next().unwrap().clone(), Default::default(), None);
+        let deployment = match transaction {
+            Transaction::Deploy(_, deployment, _) => *deployment,
+            _ => unreachable!("Expected a deployment transaction"),
+        };
+        let transactions = Transactions::from(&[Transaction::from_deployment(deployment, fee).unwrap()]);
+        assert!(matches!(transactions.verify_fee_transitions(), Err(TransactionsError::InvalidFeeTransition { .. })));
+    }
+}
@@ -20,6 +20,8 @@
 // TODO (howardwu): Remove me after tracing.
 #![allow(clippy::print_in_format_impl)]
 
+#[macro_use]
+extern crate thiserror;
 #[macro_use]
 extern crate tracing;
 
@@ -30,4 +30,221 @@ impl<N: Network> Process<N> {
         // Authorize the call.
         self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)
     }
+
+    /// Returns a preview of the authorization for a call to the program function, without signing any requests.
+    #[inline]
+    pub fn authorize_dry_run(
+        &self,
+        caller: &Address<N>,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<AuthorizationPreview<N>> {
+        // Ensure the program function exists, and the inputs match its input types.
+        self.check_call(program_id, function_name, inputs)?;
+        // Retrieve the function.
+        let stack = self.get_stack(program_id)?;
+        let function = stack.get_function(function_name)?;
+        // Determine the number of transitions.
+        let num_transitions = stack.get_number_of_calls(function_name)?;
+        // Return the preview.
+        Ok(AuthorizationPreview::new(
+            *caller,
+            *program_id,
+            *function_name,
+            inputs.len(),
+            function.outputs().len(),
+            num_transitions,
+        ))
+    }
+
+    /// Returns an estimate of the cost of executing a call to the program function, without running the prover.
+    /// Note: The constraints are read from the verifying keys, so this method errors if the verifying key
+    /// of the function, or of a function it calls, has not been synthesized or inserted.
+    #[inline]
+    pub fn estimate_cost(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<ExecutionCost> {
+        // Ensure the program function exists, and the inputs match its input types.
+        self.check_call(program_id, function_name, inputs)?;
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Return the estimate.
+        Ok(ExecutionCost::new(
+            stack.get_number_of_calls(function_name)?,
+            stack.get_number_of_constraints(function_name)?,
+        ))
+    }
+
+    /// Returns a summary of the transitions in the given authorization, without consuming it.
+    #[inline]
+    pub fn describe_authorization(&self, authorization: &Authorization<N>) -> Result<AuthorizationSummary<N>> {
+        // Prepare the program ID and function name of the fee.
+        let credits_id = ProgramID::from_str("credits.aleo")?;
+        let fee_name = Identifier::from_str("fee")?;
+
+        let mut transitions = Vec::with_capacity(authorization.len());
+        let mut fee_in_gates = None;
+        for request in authorization.to_vec_deque() {
+            // Ensure the function exists.
+            self.get_stack(request.program_id())?.get_function(request.function_name())?;
+            // Retrieve the declared fee, if this is the fee transition.
+            if request.program_id() == &credits_id && request.function_name() == &fee_name {
+                match request.inputs().get(1) {
+                    Some(Value::Plaintext(Plaintext::Literal(Literal::U64(fee), ..))) => fee_in_gates = Some(**fee),
+                    _ => bail!("The fee request does not contain a fee amount"),
+                }
+            }
+            transitions.push((*request.program_id(), *request.function_name()));
+        }
+        Ok(AuthorizationSummary::new(transitions, fee_in_gates))
+    }
+
+    /// Ensures the program function exists, and that the given inputs match its input types.
+    /// If the check fails, the error downcasts to an `AuthorizeError`.
+    #[inline]
+    pub fn check_call(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<()> {
+        // Retrieve the stack and function.
+        let (stack, function) = self.get_call_function(program_id, function_name, inputs.len())?;
+        // Ensure each input matches its input type.
+        for (index, (input, input_type)) in inputs.iter().zip(function.input_types()).enumerate() {
+            if let Err(source) = stack.matches_value_type(input, &input_type) {
+                let found = Box::new(input.clone());
+                return Err(AuthorizeError::InputTypeMismatch { index, expected: input_type, found, source }.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures each record input of the program function is among the given available records,
+    /// and that no record is spent by more than one input.
+    /// If the check fails, the error downcasts to an `AuthorizeError`.
+    #[inline]
+    pub fn check_records(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+        available_records: &[Record<N, Plaintext<N>>],
+    ) -> Result<()> {
+        // Retrieve the function.
+        let (_, function) = self.get_call_function(program_id, function_name, inputs.len())?;
+
+        // The commitments of the available records, computed once for each record type.
+        let mut available_commitments = HashMap::new();
+        let mut commitments = HashSet::with_capacity(inputs.len());
+        for (index, (input, input_type)) in inputs.iter().zip(function.input_types()).enumerate() {
+            // Retrieve the program ID and name of the record.
+            let (record_program_id, record_name) = match &input_type {
+                ValueType::Record(record_name) => (program_id, record_name),
+                ValueType::ExternalRecord(locator) => (locator.program_id(), locator.resource()),
+                _ => continue,
+            };
+            let record = match input {
+                Value::Record(record) => record,
+                _ => bail!("Input {index} is not a record"),
+            };
+            // Compute the record commitment.
+            let commitment = record.to_commitment(record_program_id, record_name)?;
+            // Ensure the record is not spent by another input.
+            if !commitments.insert(commitment) {
+                return Err(AuthorizeError::DuplicateRecord { index, commitment }.into());
+            }
+            // Ensure the record is among the available records.
+            let available = available_commitments.entry((*record_program_id, *record_name)).or_insert_with(|| {
+                available_records
+                    .iter()
+                    .filter_map(|available| available.to_commitment(record_program_id, record_name).ok())
+                    .collect::<HashSet<_>>()
+            });
+            if !available.contains(&commitment) {
+                return Err(AuthorizeError::UnknownRecord { index, commitment }.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses the given input strings against the input types of the program function.
+    /// If an input fails to parse or does not match its input type, the error identifies the input.
+    #[inline]
+    pub fn parse_inputs(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[String],
+    ) -> Result<Vec<Value<N>>> {
+        // Retrieve the stack and function.
+        let (stack, function) = self.get_call_function(program_id, function_name, inputs.len())?;
+        // Parse each input, and ensure it matches its input type.
+        inputs
+            .iter()
+            .zip_eq(function.input_types())
+            .enumerate()
+            .map(|(index, (input, input_type))| match Value::from_str(input) {
+                Ok(value) if stack.matches_value_type(&value, &input_type).is_ok() => Ok(value),
+                _ => bail!("Input {index}: expected {input_type}, got '{input}'"),
+            })
+            .collect()
+    }
+
+    /// Returns the stack and the program function, after ensuring the function takes the given number of inputs.
+    /// If the check fails, the error downcasts to an `AuthorizeError`.
+    fn get_call_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        num_inputs: usize,
+    ) -> Result<(&Stack<N>, Function<N>)> {
+        // Retrieve the stack.
+        let stack = match self.contains_program(program_id) {
+            true => self.get_stack(program_id)?,
+            false => return Err(AuthorizeError::UnknownProgram(*program_id).into()),
+        };
+        // Retrieve the function.
+        let function = match stack.program().contains_function(function_name) {
+            true => stack.get_function(function_name)?,
+            false => {
+                return Err(AuthorizeError::UnknownFunction { program: *program_id, function: *function_name }.into());
+            }
+        };
+        // Ensure the number of inputs matches the number of input types.
+        if function.inputs().len() != num_inputs {
+            return Err(AuthorizeError::InputCountMismatch {
+                program: *program_id,
+                function: *function_name,
+                expected: function.inputs().len(),
+                found: num_inputs,
+            }
+            .into());
+        }
+        Ok((stack, function))
+    }
+
+    /// Verifies the given authorization, by checking each request against the input types of its function.
+    #[inline]
+    pub fn verify_authorization(&self, authorization: &Authorization<N>) -> Result<()> {
+        // Ensure the authorization contains at least one request.
+        ensure!(!authorization.is_empty(), "The authorization does not contain any requests");
+        // Verify each request.
+        for request in authorization.to_vec_deque() {
+            // Retrieve the function.
+            let function = self.get_stack(request.program_id())?.get_function(request.function_name())?;
+            // Ensure the request is valid for the function.
+            ensure!(
+                request.verify(&function.input_types()),
+                "Failed to verify the request for '{}/{}'",
+                request.program_id(),
+                request.function_name()
+            );
+        }
+        Ok(())
+    }
 }
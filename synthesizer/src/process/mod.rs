@@ -25,22 +25,23 @@ mod execute_fee;
 
 use crate::{
     block::{Input, Transition},
-    program::{Instruction, Operand, Program},
+    program::{Function, Instruction, Operand, Program},
     snark::{ProvingKey, UniversalSRS, VerifyingKey},
     store::{ProgramStorage, ProgramStore},
 };
 use console::{
-    account::PrivateKey,
+    account::{Address, PrivateKey},
     network::prelude::*,
-    program::{Identifier, Plaintext, ProgramID, Record, Request, Response, Value},
+    program::{Identifier, Literal, Plaintext, ProgramID, Record, Request, Response, Value, ValueType},
     types::{I64, U16, U64},
 };
 
 use indexmap::IndexMap;
 use parking_lot::RwLock;
-#[cfg(test)]
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 #[cfg(feature = "aleo-cli")]
 use colored::Colorize;
@@ -242,7 +243,7 @@ pub(crate) mod test_helpers {
     use super::*;
     use crate::{Process, Program, Transition};
     use console::{
-        account::PrivateKey,
+        account::{Address, PrivateKey},
         network::Testnet3,
         program::{Identifier, Value},
     };
@@ -365,6 +366,18 @@ function compute:
         // Return the process.
         process
     }
+
+    /// Samples a call intent for `credits.aleo/mint`.
+    pub(crate) fn sample_call_intent(rng: &mut TestRng) -> CallIntent<CurrentNetwork> {
+        // Sample a recipient.
+        let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        // Construct the call intent.
+        CallIntent::new(ProgramID::from_str("credits.aleo").unwrap(), Identifier::from_str("mint").unwrap(), vec![
+            Value::from_str(&recipient.to_string()).unwrap(),
+            Value::from_str("1u64").unwrap(),
+        ])
+        .unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -1475,4 +1488,69 @@ function mint:
             store.get_value(program0.id(), &mapping_name, &Plaintext::from(Literal::Address(caller))).unwrap().unwrap();
         assert_eq!(candidate, Value::from_str("100u64").unwrap());
     }
+
+    #[test]
+    fn test_process_estimate_cost() {
+        // Initialize a new program.
+        let (string, program0) = Program::<CurrentNetwork>::parse(
+            r"
+program adder.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+        // Declare the inputs.
+        let inputs = [Value::<CurrentNetwork>::from_str("5u32").unwrap(), Value::from_str("10u32").unwrap()];
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = super::test_helpers::sample_process(&program0);
+        // Ensure the estimate requires the verifying key.
+        assert!(process.estimate_cost(program0.id(), &function_name, &inputs).is_err());
+        process.synthesize_key::<CurrentAleo, _>(program0.id(), &function_name, rng).unwrap();
+
+        // Estimate the cost of the function.
+        let cost0 = process.estimate_cost(program0.id(), &function_name, &inputs).unwrap();
+        assert_eq!(cost0.num_transitions(), 1);
+        assert!(cost0.num_constraints() > 0);
+
+        // Initialize another program, which calls the first program.
+        let (string, program1) = Program::<CurrentNetwork>::parse(
+            r"
+import adder.aleo;
+
+program double_adder.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    call adder.aleo/compute r0 r2 into r3;
+    output r3 as u32.public;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Add the program to the process.
+        process.add_program(&program1).unwrap();
+        // Ensure the estimate requires the verifying key of the caller.
+        assert!(process.estimate_cost(program1.id(), &function_name, &inputs).is_err());
+        process.synthesize_key::<CurrentAleo, _>(program1.id(), &function_name, rng).unwrap();
+
+        // Ensure the estimate sums the constraints of the caller and the callee.
+        let cost1 = process.estimate_cost(program1.id(), &function_name, &inputs).unwrap();
+        let verifying_key = process.get_verifying_key(program1.id(), &function_name).unwrap();
+        assert_eq!(cost1.num_transitions(), 2);
+        assert_eq!(cost1.num_constraints(), verifying_key.circuit_info.num_constraints + cost0.num_constraints());
+    }
 }
@@ -0,0 +1,47 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The errors returned when authorizing a call to a program function.
+#[derive(Debug, Error)]
+pub enum AuthorizeError<N: Network> {
+    #[error("Program '{0}' does not exist")]
+    UnknownProgram(ProgramID<N>),
+    #[error("Function '{function}' does not exist in program '{program}'")]
+    UnknownFunction { program: ProgramID<N>, function: Identifier<N> },
+    #[error("Function '{program}/{function}' expected {expected} inputs, got {found}")]
+    InputCountMismatch { program: ProgramID<N>, function: Identifier<N>, expected: usize, found: usize },
+    #[error("Input {index} does not match its declared type '{expected}', found '{found}': {source}")]
+    InputTypeMismatch { index: usize, expected: ValueType<N>, found: Box<Value<N>>, source: anyhow::Error },
+    #[error("Input {index} spends record '{commitment}', which is not among the available records")]
+    UnknownRecord { index: usize, commitment: Field<N> },
+    #[error("Input {index} spends record '{commitment}', which is already spent by another input")]
+    DuplicateRecord { index: usize, commitment: Field<N> },
+    #[error("Failed to authorize '{program}/{function}': {source}")]
+    Transition { program: ProgramID<N>, function: Identifier<N>, source: anyhow::Error },
+}
+
+impl<N: Network> AuthorizeError<N> {
+    /// Attributes the given error to the transition for the given program ID and function name.
+    /// Note: If the error is already an authorization error (i.e. from a nested call), it is returned as is.
+    pub(crate) fn transition(program: ProgramID<N>, function: Identifier<N>, error: anyhow::Error) -> anyhow::Error {
+        match error.is::<Self>() {
+            true => error,
+            false => Self::Transition { program, function, source: error }.into(),
+        }
+    }
+}
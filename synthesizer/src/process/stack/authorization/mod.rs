@@ -14,7 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use console::{network::prelude::*, program::Request};
+mod error;
+pub use error::*;
+
+mod preview;
+pub use preview::*;
+
+mod summary;
+pub use summary::*;
+
+use console::{
+    account::Address,
+    network::prelude::*,
+    program::{Identifier, ProgramID, Request, Value, ValueType},
+    types::Field,
+};
 
 use parking_lot::RwLock;
 use std::{collections::VecDeque, sync::Arc};
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A preview of the transitions that would be authorized for a call to a program function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuthorizationPreview<N: Network> {
+    /// The caller.
+    caller: Address<N>,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The number of inputs to the function.
+    num_inputs: usize,
+    /// The number of outputs from the function.
+    num_outputs: usize,
+    /// The number of transitions, including the function itself.
+    num_transitions: usize,
+}
+
+impl<N: Network> AuthorizationPreview<N> {
+    /// Initializes a new authorization preview.
+    pub(crate) const fn new(
+        caller: Address<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        num_inputs: usize,
+        num_outputs: usize,
+        num_transitions: usize,
+    ) -> Self {
+        Self { caller, program_id, function_name, num_inputs, num_outputs, num_transitions }
+    }
+
+    /// Returns the caller.
+    pub const fn caller(&self) -> &Address<N> {
+        &self.caller
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the number of inputs to the function.
+    pub const fn num_inputs(&self) -> usize {
+        self.num_inputs
+    }
+
+    /// Returns the number of outputs from the function.
+    pub const fn num_outputs(&self) -> usize {
+        self.num_outputs
+    }
+
+    /// Returns the number of transitions, including the function itself.
+    pub const fn num_transitions(&self) -> usize {
+        self.num_transitions
+    }
+}
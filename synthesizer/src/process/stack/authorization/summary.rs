@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A summary of the transitions in an authorization.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuthorizationSummary<N: Network> {
+    /// The program ID and function name of each transition, in order.
+    transitions: Vec<(ProgramID<N>, Identifier<N>)>,
+    /// The fee declared in the authorization (in gates), if it contains a fee transition.
+    fee_in_gates: Option<u64>,
+}
+
+impl<N: Network> AuthorizationSummary<N> {
+    /// Initializes a new authorization summary.
+    pub(crate) const fn new(transitions: Vec<(ProgramID<N>, Identifier<N>)>, fee_in_gates: Option<u64>) -> Self {
+        Self { transitions, fee_in_gates }
+    }
+
+    /// Returns the program ID and function name of each transition, in order.
+    pub fn transitions(&self) -> &[(ProgramID<N>, Identifier<N>)] {
+        &self.transitions
+    }
+
+    /// Returns the number of transitions.
+    pub fn num_transitions(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// Returns the fee declared in the authorization (in gates), if it contains a fee transition.
+    pub const fn fee_in_gates(&self) -> Option<u64> {
+        self.fee_in_gates
+    }
+}
@@ -43,14 +43,18 @@ impl<N: Network> Stack<N> {
             )
         }
 
+        // Attributes the given error to this transition.
+        let to_authorize_error = |error| AuthorizeError::transition(*self.program.id(), function_name, error);
+
         // Compute the request.
-        let request = Request::sign(private_key, *self.program.id(), function_name, inputs, &input_types, rng)?;
+        let request = Request::sign(private_key, *self.program.id(), function_name, inputs, &input_types, rng)
+            .map_err(to_authorize_error)?;
         // Initialize the authorization.
         let authorization = Authorization::new(&[request.clone()]);
         // Construct the call stack.
         let call_stack = CallStack::Authorize(vec![request], *private_key, authorization.clone());
         // Construct the authorization from the function.
-        let _response = self.execute_function::<A, R>(call_stack, rng)?;
+        let _response = self.execute_function::<A, R>(call_stack, rng).map_err(to_authorize_error)?;
         // Return the authorization.
         Ok(authorization)
     }
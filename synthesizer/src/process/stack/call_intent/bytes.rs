@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for CallIntent<N> {
+    /// Reads the call intent from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u16::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid call intent version"));
+        }
+        // Read the program ID.
+        let program_id = FromBytes::read_le(&mut reader)?;
+        // Read the function name.
+        let function_name = FromBytes::read_le(&mut reader)?;
+        // Read the number of inputs.
+        let num_inputs = u16::read_le(&mut reader)?;
+        // Ensure the number of inputs is within the allowed range.
+        if num_inputs as usize > N::MAX_INPUTS {
+            return Err(error("Call intent exceeded maximum number of inputs"));
+        }
+        // Read the inputs.
+        let inputs = (0..num_inputs).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        // Return the call intent.
+        Self::new(program_id, function_name, inputs).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for CallIntent<N> {
+    /// Writes the call intent to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        0u16.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the function name.
+        self.function_name.write_le(&mut writer)?;
+        // Write the number of inputs.
+        u16::try_from(self.inputs.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the inputs.
+        self.inputs.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new call intent.
+        let expected = crate::process::test_helpers::sample_call_intent(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, CallIntent::read_le(&expected_bytes[..])?);
+        assert!(CallIntent::<console::network::Testnet3>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID, Value},
+};
+
+/// An unsigned call to a program function, which captures what to call, but not who calls it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CallIntent<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The function inputs.
+    inputs: Vec<Value<N>>,
+}
+
+impl<N: Network> CallIntent<N> {
+    /// Initializes a new call intent for the given program ID, function name, and inputs.
+    pub fn new(program_id: ProgramID<N>, function_name: Identifier<N>, inputs: Vec<Value<N>>) -> Result<Self> {
+        // Ensure the number of inputs is within the allowed range.
+        ensure!(inputs.len() <= N::MAX_INPUTS, "Call intent exceeded maximum number of inputs");
+        // Return the new call intent.
+        Ok(Self { program_id, function_name, inputs })
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the function inputs.
+    pub fn inputs(&self) -> &[Value<N>] {
+        &self.inputs
+    }
+}
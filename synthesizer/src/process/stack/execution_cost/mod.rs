@@ -0,0 +1,43 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// An estimate of the cost of executing a call to a program function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionCost {
+    /// The number of transitions, including the function itself.
+    num_transitions: usize,
+    /// The number of constraints, summed over all transitions.
+    num_constraints: usize,
+}
+
+impl ExecutionCost {
+    /// Initializes a new execution cost.
+    pub(crate) const fn new(num_transitions: usize, num_constraints: usize) -> Self {
+        Self { num_transitions, num_constraints }
+    }
+
+    /// Returns the number of transitions, including the function itself.
+    pub const fn num_transitions(&self) -> usize {
+        self.num_transitions
+    }
+
+    /// Returns the number of constraints, summed over all transitions.
+    pub const fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+}
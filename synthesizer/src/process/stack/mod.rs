@@ -17,12 +17,18 @@
 mod authorization;
 pub use authorization::*;
 
+mod call_intent;
+pub use call_intent::*;
+
 mod deployment;
 pub use deployment::*;
 
 mod execution;
 pub use execution::*;
 
+mod execution_cost;
+pub use execution_cost::*;
+
 mod fee;
 pub use fee::*;
 
@@ -307,6 +313,29 @@ impl<N: Network> Stack<N> {
         Ok(num_calls)
     }
 
+    /// Returns the expected number of constraints for the given function name, summed over all calls.
+    /// Note: This method requires the verifying key of the function, and of each function it calls.
+    #[inline]
+    pub fn get_number_of_constraints(&self, function_name: &Identifier<N>) -> Result<usize> {
+        // Determine the number of constraints for this function.
+        let mut num_constraints = self.get_verifying_key(function_name)?.circuit_info.num_constraints;
+        for instruction in self.get_function(function_name)?.instructions() {
+            if let Instruction::Call(call) = instruction {
+                // Determine if this is a function call.
+                if call.is_function_call(self)? {
+                    // Increment by the number of constraints.
+                    num_constraints += match call.operator() {
+                        CallOperator::Locator(locator) => self
+                            .get_external_stack(locator.program_id())?
+                            .get_number_of_constraints(locator.resource())?,
+                        CallOperator::Resource(resource) => self.get_number_of_constraints(resource)?,
+                    };
+                }
+            }
+        }
+        Ok(num_constraints)
+    }
+
     /// Returns the register types for the given closure or function name.
     #[inline]
     pub fn get_register_types(&self, name: &Identifier<N>) -> Result<&RegisterTypes<N>> {
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallStack, Opcode, Operand, Registers, Stack};
+use crate::{AuthorizeError, CallStack, Opcode, Operand, Registers, Stack};
 use console::{
     network::prelude::*,
     program::{Identifier, Locator, Register, RegisterType, Request, ValueType},
@@ -292,9 +292,40 @@ impl<N: Network> Call<N> {
                 let rng = &mut rand::thread_rng();
 
                 match registers.call_stack() {
-                    // If the circuit is in authorize or synthesize mode, then add any external calls to the stack.
-                    CallStack::Authorize(_, private_key, authorization)
-                    | CallStack::Synthesize(_, private_key, authorization) => {
+                    // If the circuit is in authorize mode, then add any external calls to the stack.
+                    CallStack::Authorize(_, private_key, authorization) => {
+                        // Attributes the given error to the called transition.
+                        let to_authorize_error =
+                            |error| AuthorizeError::transition(*substack.program_id(), *function.name(), error);
+
+                        // Compute the request.
+                        let request = Request::sign(
+                            &private_key,
+                            *substack.program_id(),
+                            *function.name(),
+                            &inputs,
+                            &function.input_types(),
+                            rng,
+                        )
+                        .map_err(to_authorize_error)?;
+
+                        // Retrieve the call stack.
+                        let mut call_stack = registers.call_stack();
+                        // Push the request onto the call stack.
+                        call_stack.push(request.clone())?;
+
+                        // Add the request to the authorization.
+                        authorization.push(request.clone());
+
+                        // Execute the request.
+                        let response =
+                            substack.execute_function::<A, _>(call_stack, rng).map_err(to_authorize_error)?;
+
+                        // Return the request and response.
+                        (request, response)
+                    }
+                    // If the circuit is in synthesize mode, then add any external calls to the stack.
+                    CallStack::Synthesize(_, private_key, authorization) => {
                         // Compute the request.
                         let request = Request::sign(
                             &private_key,
@@ -18,6 +18,8 @@ use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Authorizes a call to the program function for the given inputs.
+    /// If the program function is unknown, the inputs do not match the function, or a transition fails to authorize,
+    /// the error downcasts to an `AuthorizeError`.
     #[inline]
     pub fn authorize<R: Rng + CryptoRng>(
         &self,
@@ -27,6 +29,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         inputs: &[Value<N>],
         rng: &mut R,
     ) -> Result<Authorization<N>> {
+        // Ensure the program function exists, and the inputs match its input types.
+        self.process.read().check_call(program_id, &function_name, inputs)?;
+
         // Compute the core logic.
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
@@ -49,4 +54,660 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Process the logic.
         process!(self, logic)
     }
+
+    /// Authorizes a call to the program function for the given inputs, using a ChaCha RNG seeded with the given seed.
+    /// The same seed always yields the same authorization.
+    #[inline]
+    pub fn authorize_with_seed(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: &ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        seed: [u8; 32],
+    ) -> Result<Authorization<N>> {
+        self.authorize(private_key, program_id, function_name, inputs, &mut ChaCha20Rng::from_seed(seed))
+    }
+
+    /// Authorizes a call to the program function for the given inputs, after parsing each input string
+    /// against the input types of the function.
+    #[inline]
+    pub fn authorize_from_strings<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: &ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[String],
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Parse the inputs.
+        let inputs = self.process.read().parse_inputs(program_id, &function_name, inputs)?;
+        // Authorize the call.
+        self.authorize(private_key, program_id, function_name, &inputs, rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs, after ensuring each record input
+    /// is among the given available records, and that no record is spent by more than one input.
+    /// If a record is unavailable, the error downcasts to `AuthorizeError::UnknownRecord`.
+    #[inline]
+    pub fn authorize_checked<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: &ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        available_records: &[Record<N, Plaintext<N>>],
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        {
+            let process = self.process.read();
+            // Ensure the program function exists, and the inputs match its input types.
+            process.check_call(program_id, &function_name, inputs)?;
+            // Ensure the record inputs are available.
+            process.check_records(program_id, &function_name, inputs, available_records)?;
+        }
+        // Authorize the call.
+        self.authorize(private_key, program_id, function_name, inputs, rng)
+    }
+
+    /// Authorizes a fee for the given private key, credits record, and fee amount (in gates).
+    #[inline]
+    pub fn authorize_fee<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        credits: Record<N, Plaintext<N>>,
+        fee_in_gates: u64,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Prepare the program ID and function name.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let function_name = Identifier::from_str("fee")?;
+        // Construct the inputs.
+        let inputs = [Value::Record(credits), Value::from_str(&format!("{fee_in_gates}u64"))?];
+        // Authorize the call to fee.
+        self.authorize(private_key, &program_id, function_name, &inputs, rng)
+    }
+
+    /// Authorizes a call to each program function for the given inputs, returning one authorization per request.
+    /// If a request fails to authorize, the error identifies the index of the failing request,
+    /// and downcasts to an `AuthorizeError` as in `authorize`.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn authorize_many<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        requests: &[(ProgramID<N>, Identifier<N>, Vec<Value<N>>)],
+        rng: &mut R,
+    ) -> Result<Vec<Authorization<N>>> {
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                let process = $process;
+
+                // Prepare the private key.
+                let private_key = cast_ref!(&private_key as PrivateKey<$network>);
+
+                // Compute the authorization for each request.
+                let mut authorizations = Vec::with_capacity(requests.len());
+                for (index, (program_id, function_name, inputs)) in requests.iter().enumerate() {
+                    // Prepare the inputs.
+                    let program_id = cast_ref!(&program_id as ProgramID<$network>);
+                    let function_name = cast_ref!(&function_name as Identifier<$network>);
+                    let inputs = cast_ref!(&inputs as Vec<Value<$network>>);
+
+                    // Ensure the program function exists, and the inputs match its input types.
+                    // Then, compute the authorization.
+                    let authorization = process
+                        .check_call(program_id, function_name, inputs)
+                        .and_then(|_| {
+                            process.authorize::<$aleo, _>(private_key, program_id, *function_name, inputs, rng)
+                        })
+                        .map_err(|error| error.context(format!("Failed to authorize request {index}")))?;
+                    authorizations.push(authorization);
+                }
+
+                // Return the authorizations.
+                Ok(cast_ref!(authorizations as Vec<Authorization<N>>).clone())
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
+
+    /// Authorizes a call to the program function for the given inputs, and verifies the resulting authorization.
+    /// Note: This method performs an additional verification; performance-sensitive callers may use `authorize`.
+    #[inline]
+    pub fn authorize_and_verify<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: &ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Compute the authorization.
+        let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
+        // Verify the authorization.
+        self.verify_authorization(&authorization)?;
+        // Return the authorization.
+        Ok(authorization)
+    }
+
+    /// Returns a preview of the authorization for a call to the program function, without signing any requests.
+    #[inline]
+    pub fn authorize_dry_run(
+        &self,
+        caller: &Address<N>,
+        program_id: &ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<AuthorizationPreview<N>> {
+        self.process.read().authorize_dry_run(caller, program_id, &function_name, inputs)
+    }
+
+    /// Returns an estimate of the cost of executing a call to the program function, without running the prover.
+    /// Note: This method errors if the verifying key of the function, or of a function it calls, is not yet available.
+    #[inline]
+    pub fn estimate_cost(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<ExecutionCost> {
+        self.process.read().estimate_cost(program_id, &function_name, inputs)
+    }
+
+    /// Returns a summary of the transitions in the given authorization, without consuming it.
+    #[inline]
+    pub fn describe_authorization(&self, authorization: &Authorization<N>) -> Result<AuthorizationSummary<N>> {
+        self.process.read().describe_authorization(authorization)
+    }
+
+    /// Verifies the given authorization.
+    #[inline]
+    pub fn verify_authorization(&self, authorization: &Authorization<N>) -> Result<()> {
+        self.process.read().verify_authorization(authorization)
+    }
+
+    /// Returns a call intent for the program function, after ensuring the inputs match the function.
+    #[inline]
+    pub fn build_intent(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<CallIntent<N>> {
+        // Ensure the program function exists, and the inputs match its input types.
+        self.process.read().check_call(program_id, &function_name, inputs)?;
+        // Construct the call intent.
+        CallIntent::new(*program_id, function_name, inputs.to_vec())
+    }
+
+    /// Authorizes the given call intent, as a call by the given private key.
+    #[inline]
+    pub fn authorize_intent<R: Rng + CryptoRng>(
+        &self,
+        intent: &CallIntent<N>,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize(private_key, intent.program_id(), *intent.function_name(), intent.inputs(), rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::AuthorizeError;
+    use console::{account::Address, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_authorize_error_context() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        // Initialize a record that does not belong to the caller.
+        let other = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let record = Value::<CurrentNetwork>::from_str(&format!(
+            "{{ owner: {other}.private, gates: 5u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+
+        // Authorize a transfer of the record.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+        let inputs = [record, Value::from_str(&caller.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let error = vm.authorize(&caller_private_key, &program_id, function_name, &inputs, rng).err().unwrap();
+
+        // Ensure the error identifies the failing transition.
+        match error.downcast_ref::<AuthorizeError<CurrentNetwork>>() {
+            Some(AuthorizeError::Transition { program, function, source }) => {
+                assert_eq!(*program, program_id);
+                assert_eq!(*function, function_name);
+                assert!(source.to_string().contains("must belong to the signer"));
+            }
+            _ => panic!("Expected a transition authorization error, found '{error}'"),
+        }
+    }
+
+    #[test]
+    fn test_authorize_and_verify() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Authorize and verify a mint.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        let inputs = [Value::from_str(&caller.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let authorization =
+            vm.authorize_and_verify(&caller_private_key, &program_id, function_name, &inputs, rng).unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Ensure the authorization verifies again.
+        assert!(vm.verify_authorization(&authorization).is_ok());
+        // Ensure an empty authorization is rejected.
+        assert!(vm.verify_authorization(&Authorization::new(&[])).is_err());
+    }
+
+    #[test]
+    fn test_build_and_authorize_intent() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Build an intent to mint.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        let inputs = [Value::from_str(&caller.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let intent = vm.build_intent(&program_id, function_name, &inputs).unwrap();
+        assert_eq!(intent.program_id(), &program_id);
+        assert_eq!(intent.function_name(), &function_name);
+        assert_eq!(intent.inputs(), &inputs);
+
+        // Ensure invalid inputs are rejected.
+        assert!(vm.build_intent(&program_id, function_name, &inputs[..1]).is_err());
+        assert!(vm.build_intent(&program_id, function_name, &[inputs[1].clone(), inputs[0].clone()]).is_err());
+        assert!(vm.build_intent(&program_id, Identifier::from_str("unknown").unwrap(), &inputs).is_err());
+
+        // Authorize the intent.
+        let authorization = vm.authorize_intent(&intent, &caller_private_key, rng).unwrap();
+        assert_eq!(authorization.len(), 1);
+        assert!(vm.verify_authorization(&authorization).is_ok());
+    }
+
+    #[test]
+    fn test_authorize_many() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        // Initialize a record that belongs to the caller.
+        let record = Value::<CurrentNetwork>::from_str(&format!(
+            "{{ owner: {caller}.private, gates: 5u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+
+        // Authorize a mint and a split in one batch.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mint = Identifier::from_str("mint").unwrap();
+        let split = Identifier::from_str("split").unwrap();
+        let mut requests = vec![
+            (program_id, mint, vec![Value::from_str(&caller.to_string()).unwrap(), Value::from_str("1u64").unwrap()]),
+            (program_id, split, vec![record, Value::from_str("2u64").unwrap()]),
+        ];
+        let authorizations = vm.authorize_many(&caller_private_key, &requests, rng).unwrap();
+        assert_eq!(authorizations.len(), 2);
+        assert_eq!(authorizations[0].peek_next().unwrap().function_name(), &mint);
+        assert_eq!(authorizations[1].peek_next().unwrap().function_name(), &split);
+
+        // Ensure the failing request is identified.
+        requests.push((program_id, Identifier::from_str("unknown").unwrap(), vec![]));
+        let error = vm.authorize_many(&caller_private_key, &requests, rng).err().unwrap();
+        assert!(error.to_string().contains("request 2"));
+        assert!(matches!(
+            error.downcast_ref::<AuthorizeError<CurrentNetwork>>(),
+            Some(AuthorizeError::UnknownFunction { .. })
+        ));
+
+        // Ensure the inputs of each request are checked before it is authorized.
+        requests[2] = (program_id, mint, vec![Value::from_str("1u64").unwrap(), Value::from_str("1u64").unwrap()]);
+        let error = vm.authorize_many(&caller_private_key, &requests, rng).err().unwrap();
+        assert!(error.to_string().contains("request 2"));
+        assert!(matches!(
+            error.downcast_ref::<AuthorizeError<CurrentNetwork>>(),
+            Some(AuthorizeError::InputTypeMismatch { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_authorize_unknown_program_and_function() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let inputs = [Value::from_str(&caller.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+
+        // Ensure an unknown program is reported.
+        let program_id = ProgramID::from_str("unknown.aleo").unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        let error = vm.authorize(&caller_private_key, &program_id, function_name, &inputs, rng).err().unwrap();
+        match error.downcast_ref::<AuthorizeError<CurrentNetwork>>() {
+            Some(AuthorizeError::UnknownProgram(program)) => assert_eq!(*program, program_id),
+            _ => panic!("Expected an unknown program error, found '{error}'"),
+        }
+
+        // Ensure an unknown function is reported.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("unknown").unwrap();
+        let error = vm.authorize(&caller_private_key, &program_id, function_name, &inputs, rng).err().unwrap();
+        match error.downcast_ref::<AuthorizeError<CurrentNetwork>>() {
+            Some(AuthorizeError::UnknownFunction { program, function }) => {
+                assert_eq!(*program, program_id);
+                assert_eq!(*function, function_name);
+            }
+            _ => panic!("Expected an unknown function error, found '{error}'"),
+        }
+
+        // Ensure an input type mismatch is reported.
+        let function_name = Identifier::from_str("mint").unwrap();
+        let inputs = [inputs[1].clone(), inputs[0].clone()];
+        let error = vm.authorize(&caller_private_key, &program_id, function_name, &inputs, rng).err().unwrap();
+        match error.downcast_ref::<AuthorizeError<CurrentNetwork>>() {
+            Some(AuthorizeError::InputTypeMismatch { index, found, source, .. }) => {
+                assert_eq!(*index, 0);
+                assert_eq!(**found, inputs[0]);
+                // Ensure the reason for the mismatch is preserved.
+                assert!(!source.to_string().is_empty());
+                assert!(error.to_string().ends_with(&source.to_string()), "{error}");
+            }
+            _ => panic!("Expected an input type mismatch error, found '{error}'"),
+        }
+    }
+
+    #[test]
+    fn test_authorize_input_count_mismatch() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        let inputs = [
+            Value::from_str(&caller.to_string()).unwrap(),
+            Value::from_str("1u64").unwrap(),
+            Value::from_str("1u64").unwrap(),
+        ];
+
+        // Ensure too few and too many inputs are reported.
+        for num_inputs in [0, 1, 3] {
+            let error = vm
+                .authorize(&caller_private_key, &program_id, function_name, &inputs[..num_inputs], rng)
+                .err()
+                .unwrap();
+            match error.downcast_ref::<AuthorizeError<CurrentNetwork>>() {
+                Some(AuthorizeError::InputCountMismatch { expected, found, .. }) => {
+                    assert_eq!(*expected, 2);
+                    assert_eq!(*found, num_inputs);
+                }
+                _ => panic!("Expected an input count mismatch error, found '{error}'"),
+            }
+            assert!(error.to_string().contains(&format!("expected 2 inputs, got {num_inputs}")));
+        }
+    }
+
+    #[test]
+    fn test_authorize_dry_run() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the inputs.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        let inputs = [Value::from_str(&caller.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+
+        // Preview the authorization.
+        let preview = vm.authorize_dry_run(&caller, &program_id, function_name, &inputs).unwrap();
+        assert_eq!(preview.caller(), &caller);
+        assert_eq!(preview.program_id(), &program_id);
+        assert_eq!(preview.function_name(), &function_name);
+        assert_eq!(preview.num_inputs(), 2);
+        assert_eq!(preview.num_outputs(), 1);
+
+        // Ensure the preview matches the real authorization.
+        let authorization = vm.authorize(&caller_private_key, &program_id, function_name, &inputs, rng).unwrap();
+        assert_eq!(preview.num_transitions(), authorization.len());
+
+        // Ensure the preview rejects mismatched inputs.
+        assert!(vm.authorize_dry_run(&caller, &program_id, function_name, &inputs[..1]).is_err());
+    }
+
+    #[test]
+    fn test_authorize_with_seed() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the inputs.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        let inputs = [Value::from_str(&caller.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+
+        // Authorize the same call twice with the same seed.
+        let authorize = |seed| {
+            let authorization =
+                vm.authorize_with_seed(&caller_private_key, &program_id, function_name, &inputs, seed).unwrap();
+            authorization.to_vec_deque().into_iter().map(|request| request.to_bytes_le().unwrap()).collect::<Vec<_>>()
+        };
+        let expected = authorize([7u8; 32]);
+        assert_eq!(expected, authorize([7u8; 32]));
+
+        // Ensure a different seed yields a different authorization.
+        assert_ne!(expected, authorize([8u8; 32]));
+    }
+
+    #[test]
+    fn test_authorize_fee() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        // Initialize a record that belongs to the caller.
+        let credits = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {caller}.private, gates: 5u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+
+        // Authorize the fee.
+        let authorization = vm.authorize_fee(&caller_private_key, credits, 1, rng).unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Ensure the authorization targets the credits program's fee function.
+        let request = authorization.peek_next().unwrap();
+        assert_eq!(request.program_id(), &ProgramID::from_str("credits.aleo").unwrap());
+        assert_eq!(request.function_name(), &Identifier::from_str("fee").unwrap());
+        assert!(vm.verify_authorization(&authorization).is_ok());
+    }
+
+    #[test]
+    fn test_authorize_from_strings() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Authorize a mint from strings.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        let inputs = [caller.to_string(), "1u64".to_string()];
+        let authorization =
+            vm.authorize_from_strings(&caller_private_key, &program_id, function_name, &inputs, rng).unwrap();
+        assert_eq!(authorization.len(), 1);
+        assert_eq!(authorization.peek_next().unwrap().inputs(), &[
+            Value::from_str(&caller.to_string()).unwrap(),
+            Value::from_str("1u64").unwrap()
+        ]);
+
+        // Ensure a type mismatch identifies the input.
+        let inputs = [caller.to_string(), "1u32".to_string()];
+        let error =
+            vm.authorize_from_strings(&caller_private_key, &program_id, function_name, &inputs, rng).err().unwrap();
+        assert!(error.to_string().contains("Input 1: expected u64.private, got '1u32'"), "{error}");
+
+        // Ensure a malformed input identifies the input.
+        let inputs = ["not a value".to_string(), "1u64".to_string()];
+        let error =
+            vm.authorize_from_strings(&caller_private_key, &program_id, function_name, &inputs, rng).err().unwrap();
+        assert!(error.to_string().starts_with("Input 0: expected address.private"), "{error}");
+    }
+
+    #[test]
+    fn test_estimate_cost() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Estimate the cost of a mint.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        let inputs = [Value::from_str(&caller.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let cost = vm.estimate_cost(&program_id, function_name, &inputs).unwrap();
+        assert_eq!(cost.num_transitions(), 1);
+        assert!((1_000..1 << 21).contains(&cost.num_constraints()), "{}", cost.num_constraints());
+    }
+
+    #[test]
+    fn test_describe_authorization() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Authorize a mint.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        let inputs = [Value::from_str(&caller.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let authorization = vm.authorize(&caller_private_key, &program_id, function_name, &inputs, rng).unwrap();
+
+        // Ensure the summary matches the request, without consuming the authorization.
+        let summary = vm.describe_authorization(&authorization).unwrap();
+        assert_eq!(summary.num_transitions(), 1);
+        assert_eq!(summary.transitions(), &[(program_id, function_name)]);
+        assert_eq!(summary.fee_in_gates(), None);
+        assert_eq!(authorization.len(), 1);
+
+        // Ensure the summary includes the declared fee.
+        let credits = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {caller}.private, gates: 5u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+        let authorization = vm.authorize_fee(&caller_private_key, credits, 3, rng).unwrap();
+        let summary = vm.describe_authorization(&authorization).unwrap();
+        assert_eq!(summary.transitions(), &[(program_id, Identifier::from_str("fee").unwrap())]);
+        assert_eq!(summary.fee_in_gates(), Some(3));
+    }
+
+    #[test]
+    fn test_authorize_checked() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        // Initialize two records that belong to the caller.
+        let sample_record = |gates: u64| {
+            Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+                "{{ owner: {caller}.private, gates: {gates}u64.private, _nonce: 0group.public }}"
+            ))
+            .unwrap()
+        };
+        let (record, other_record) = (sample_record(5), sample_record(6));
+
+        // Prepare the inputs.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+        let inputs = [
+            Value::Record(record.clone()),
+            Value::from_str(&caller.to_string()).unwrap(),
+            Value::from_str("1u64").unwrap(),
+        ];
+
+        // Ensure an available record is authorized.
+        let available_records = [other_record.clone(), record.clone()];
+        let authorization = vm
+            .authorize_checked(&caller_private_key, &program_id, function_name, &inputs, &available_records, rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Ensure a record absent from the available records is rejected.
+        let error = vm
+            .authorize_checked(&caller_private_key, &program_id, function_name, &inputs, &[other_record], rng)
+            .err()
+            .unwrap();
+        let expected = record.to_commitment(&program_id, &Identifier::from_str("credits").unwrap()).unwrap();
+        assert!(matches!(
+            error.downcast_ref::<AuthorizeError<CurrentNetwork>>(),
+            Some(AuthorizeError::UnknownRecord { index: 0, commitment }) if commitment == &expected
+        ));
+
+        // Ensure a record spent by more than one input is rejected.
+        let function_name = Identifier::from_str("join").unwrap();
+        let inputs = [Value::Record(record.clone()), Value::Record(record)];
+        let error = vm
+            .authorize_checked(&caller_private_key, &program_id, function_name, &inputs, &available_records, rng)
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error.downcast_ref::<AuthorizeError<CurrentNetwork>>(),
+            Some(AuthorizeError::DuplicateRecord { index: 1, commitment }) if commitment == &expected
+        ));
+    }
 }
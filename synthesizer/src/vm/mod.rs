@@ -27,17 +27,31 @@ use crate::{
     block::{Block, Transaction, Transactions, Transition},
     cast_ref,
     process,
-    process::{Authorization, Deployment, Execution, Fee, Inclusion, InclusionAssignment, Process},
+    process::{
+        Authorization,
+        AuthorizationPreview,
+        AuthorizationSummary,
+        CallIntent,
+        Deployment,
+        Execution,
+        ExecutionCost,
+        Fee,
+        Inclusion,
+        InclusionAssignment,
+        Process,
+    },
     program::Program,
     store::{BlockStore, ConsensusStorage, ConsensusStore, ProgramStore, TransactionStore, TransitionStore},
 };
 use console::{
-    account::PrivateKey,
+    account::{Address, PrivateKey},
     network::prelude::*,
     program::{Identifier, Plaintext, ProgramID, Record, Response, Value},
 };
 
 use parking_lot::RwLock;
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -25,3 +25,13 @@ where
     let mut uniq = HashSet::new();
     !iter.into_iter().all(move |x| uniq.insert(x))
 }
+
+/// Returns the first element of the given iterator that was already seen, if one exists.
+pub fn find_duplicate<T>(iter: T) -> Option<T::Item>
+where
+    T: IntoIterator,
+    T::Item: Eq + Hash + Clone,
+{
+    let mut uniq = HashSet::new();
+    iter.into_iter().find(move |x| !uniq.insert(x.clone()))
+}